@@ -1,7 +1,7 @@
 use halo2::{
     arithmetic::Field,
     circuit::{AssignedCell, Cell, Region, Value},
-    plonk::{Advice, Column, Error, Fixed, Selector},
+    plonk::{Advice, Column, Error, Fixed, Instance, Selector},
 };
 
 pub mod utils;
@@ -55,6 +55,21 @@ impl<'a, F: Field> RegionCtx<'a, F> {
             .assign_advice(annotation, column, self.offset, || value)
     }
 
+    pub fn assign_advice_from_instance<A, AR>(
+        &mut self,
+        annotation: A,
+        instance: Column<Instance>,
+        row: usize,
+        advice: Column<Advice>,
+    ) -> Result<AssignedCell<F, F>, Error>
+    where
+        A: Fn() -> AR,
+        AR: Into<String>,
+    {
+        self.region
+            .assign_advice_from_instance(annotation, instance, row, advice, self.offset)
+    }
+
     pub fn constrain_equal(&mut self, cell_0: Cell, cell_1: Cell) -> Result<(), Error> {
         self.region.constrain_equal(cell_0, cell_1)
     }
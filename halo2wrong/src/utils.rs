@@ -77,6 +77,19 @@ pub fn mock_prover_verify<F: FromUniformBytes<64> + Ord, C: Circuit<F>>(
     )
 }
 
+pub fn mock_prover_verify_fails<F: FromUniformBytes<64> + Ord, C: Circuit<F>>(
+    circuit: &C,
+    instance: Vec<Vec<F>>,
+) {
+    let dimension = DimensionMeasurement::measure(circuit).unwrap();
+    let prover = MockProver::run(dimension.k(), circuit, instance)
+        .unwrap_or_else(|err| panic!("{:#?}", err));
+    assert_ne!(
+        prover.verify_at_rows(dimension.advice_range(), dimension.advice_range()),
+        Ok(())
+    )
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Dimension {
     blinding_factor: u64,
@@ -1,7 +1,10 @@
+use std::cell::Cell;
+use std::ops::Deref;
 use std::rc::Rc;
 
-use super::{AssignedInteger, AssignedLimb, UnassignedInteger};
-use crate::instructions::{IntegerInstructions, Range};
+use super::{AssignedInteger, AssignedLimb, SmallInteger, UnassignedInteger};
+use crate::instructions::{CommitInstructions, HashInstructions, IntegerInstructions, Range};
+use crate::relations::RelationRecorder;
 use crate::rns::{Common, Integer, Rns};
 use halo2::halo2curves::ff::PrimeField;
 use halo2::plonk::Error;
@@ -18,10 +21,22 @@ mod assert_in_field;
 mod assert_not_zero;
 mod assert_zero;
 mod assign;
+mod cbrt;
+#[cfg(feature = "checksum")]
+mod checksum;
+mod commitment;
+mod decompress;
+mod det2;
 mod div;
+mod gcd;
 mod invert;
+mod merkle;
 mod mul;
+mod reconstruct_secret;
 mod reduce;
+mod reduce_mod_assigned;
+mod rlc;
+mod small;
 mod square;
 
 /// Configuration for [`IntegerChip`]
@@ -44,6 +59,17 @@ impl IntegerConfig {
     }
 }
 
+/// Reduction policy applied to results of additive operations
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ReductionPolicy {
+    /// Results are reduced only when they are about to exceed limits of a
+    /// following operation
+    #[default]
+    Lazy,
+    /// Results of additive operations are reduced right away
+    Eager,
+}
+
 /// Chip for integer instructions
 #[derive(Clone, Debug)]
 pub struct IntegerChip<
@@ -58,6 +84,80 @@ pub struct IntegerChip<
     main_gate: MainGate<N>,
     /// Residue number system used to represent the integers
     rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    /// Optional sink of enforced relations
+    relations: Option<RelationRecorder>,
+    /// Reduction policy for results of additive operations
+    policy: ReductionPolicy,
+    /// Number of reductions applied so far, shared with views
+    reductions: Rc<Cell<usize>>,
+    /// Optional receiver of operation spans
+    span_callback: Option<SpanCallback>,
+    /// Depth of nested operations, only outermost ones emit spans
+    span_depth: Rc<Cell<usize>>,
+}
+
+/// Operations that record every relation their gates enforce. Using any
+/// other operation with a [`RelationRecorder`] attached marks the export as
+/// incomplete.
+const RECORDED_OPS: &[&str] = &[
+    "add",
+    "add_add",
+    "add_constant",
+    "assert_equal",
+    "assert_strict_bit",
+    "assert_strict_equal",
+    "assert_strict_one",
+    "assert_strict_zero",
+    "assert_zero",
+    "assign_constant",
+    "assign_integer",
+    "det2",
+    "div",
+    "div_incomplete",
+    "invert",
+    "invert_incomplete",
+    "mul",
+    "mul2",
+    "mul3",
+    "mul_constant",
+    "mul_into_one",
+    "neg",
+    "reduce",
+    "square",
+    "sub",
+    "sub_sub",
+];
+
+/// Callback that receives operation name, start offset and end offset of
+/// the rows that an operation of [`IntegerChip`] occupies in a region
+#[derive(Clone)]
+pub struct SpanCallback(Rc<dyn Fn(&str, usize, usize)>);
+
+impl std::fmt::Debug for SpanCallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SpanCallback").finish()
+    }
+}
+
+/// View of an [`IntegerChip`] with an overridden [`ReductionPolicy`]. It
+/// shares configuration, [`Rns`] and reduction counter with the chip it is
+/// created from.
+#[derive(Clone, Debug)]
+pub struct IntegerChipView<
+    W: PrimeField,
+    N: PrimeField,
+    const NUMBER_OF_LIMBS: usize,
+    const BIT_LEN_LIMB: usize,
+>(IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>);
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize> Deref
+    for IntegerChipView<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    type Target = IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
 }
 
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
@@ -90,8 +190,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         // TODO: external integer might have different parameter settings
         a: &AssignedInteger<T, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let to_be_reduced = self.new_assigned_integer(a.limbs(), a.native().clone());
-        self.reduce(ctx, &to_be_reduced)
+        self.span(ctx, "reduce_external", |ctx| {
+            let to_be_reduced = self.new_assigned_integer(a.limbs(), a.native().clone());
+            self.reduce(ctx, &to_be_reduced)
+        })
     }
 
     fn assign_integer(
@@ -100,7 +202,9 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         integer: UnassignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         range: Range,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        self.assign_integer_generic(ctx, integer, range)
+        self.span(ctx, "assign_integer", |ctx| {
+            self.assign_integer_generic(ctx, integer, range)
+        })
     }
 
     fn assign_constant(
@@ -108,7 +212,9 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         integer: W,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        self.assign_constant_generic(ctx, integer)
+        self.span(ctx, "assign_constant", |ctx| {
+            self.assign_constant_generic(ctx, integer)
+        })
     }
 
     fn decompose(
@@ -116,24 +222,26 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         integer: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<Vec<AssignedCondition<N>>, Error> {
-        self.assert_in_field(ctx, integer)?;
-
-        let main_gate = self.main_gate();
-
-        let mut decomposed = Vec::new();
-        for idx in 0..NUMBER_OF_LIMBS {
-            let number_of_bits = if idx == NUMBER_OF_LIMBS - 1 {
-                self.rns.wrong_modulus.bits() as usize % BIT_LEN_LIMB
-            } else {
-                BIT_LEN_LIMB
-            };
-            let decomposed_limb = main_gate.to_bits(ctx, integer.limb(idx), number_of_bits)?;
-            decomposed.extend(decomposed_limb);
-        }
+        self.span(ctx, "decompose", |ctx| {
+            self.assert_in_field(ctx, integer)?;
+
+            let main_gate = self.main_gate();
+
+            let mut decomposed = Vec::new();
+            for idx in 0..NUMBER_OF_LIMBS {
+                let number_of_bits = if idx == NUMBER_OF_LIMBS - 1 {
+                    self.rns.wrong_modulus.bits() as usize % BIT_LEN_LIMB
+                } else {
+                    BIT_LEN_LIMB
+                };
+                let decomposed_limb = main_gate.to_bits(ctx, integer.limb(idx), number_of_bits)?;
+                decomposed.extend(decomposed_limb);
+            }
 
-        assert_eq!(decomposed.len(), self.rns.wrong_modulus.bits() as usize);
+            assert_eq!(decomposed.len(), self.rns.wrong_modulus.bits() as usize);
 
-        Ok(decomposed)
+            Ok(decomposed)
+        })
     }
 
     fn add(
@@ -142,11 +250,14 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let (a, b) = (
-            &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?,
-            &self.reduce_if_limb_values_exceeds_unreduced(ctx, b)?,
-        );
-        self.add_generic(ctx, a, b)
+        self.span(ctx, "add", |ctx| {
+            let (a, b) = (
+                &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?,
+                &self.reduce_if_limb_values_exceeds_unreduced(ctx, b)?,
+            );
+            let c = self.add_generic(ctx, a, b)?;
+            self.apply_policy(ctx, c)
+        })
     }
 
     fn add_add(
@@ -156,12 +267,15 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         b_0: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b_1: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let (a, b_0, b_1) = (
-            &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?,
-            &self.reduce_if_limb_values_exceeds_unreduced(ctx, b_0)?,
-            &self.reduce_if_limb_values_exceeds_unreduced(ctx, b_1)?,
-        );
-        self.add_add_generic(ctx, a, b_0, b_1)
+        self.span(ctx, "add_add", |ctx| {
+            let (a, b_0, b_1) = (
+                &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?,
+                &self.reduce_if_limb_values_exceeds_unreduced(ctx, b_0)?,
+                &self.reduce_if_limb_values_exceeds_unreduced(ctx, b_1)?,
+            );
+            let c = self.add_add_generic(ctx, a, b_0, b_1)?;
+            self.apply_policy(ctx, c)
+        })
     }
 
     fn add_constant(
@@ -170,8 +284,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let a = &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?;
-        self.add_constant_generic(ctx, a, b)
+        self.span(ctx, "add_constant", |ctx| {
+            let a = &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?;
+            let c = self.add_constant_generic(ctx, a, b)?;
+            self.apply_policy(ctx, c)
+        })
     }
 
     fn mul2(
@@ -179,7 +296,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        self.mul2_generic(ctx, a)
+        self.span(ctx, "mul2", |ctx| {
+            let c = self.mul2_generic(ctx, a)?;
+            self.apply_policy(ctx, c)
+        })
     }
 
     fn mul3(
@@ -187,7 +307,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        self.mul3_generic(ctx, a)
+        self.span(ctx, "mul3", |ctx| {
+            let c = self.mul3_generic(ctx, a)?;
+            self.apply_policy(ctx, c)
+        })
     }
 
     fn sub(
@@ -196,11 +319,14 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let (a, b) = (
-            &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?,
-            &self.reduce_if_limb_values_exceeds_unreduced(ctx, b)?,
-        );
-        self.sub_generic(ctx, a, b)
+        self.span(ctx, "sub", |ctx| {
+            let (a, b) = (
+                &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?,
+                &self.reduce_if_limb_values_exceeds_unreduced(ctx, b)?,
+            );
+            let c = self.sub_generic(ctx, a, b)?;
+            self.apply_policy(ctx, c)
+        })
     }
 
     fn sub_sub(
@@ -210,12 +336,15 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         b_0: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b_1: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let (a, b_0, b_1) = (
-            &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?,
-            &self.reduce_if_limb_values_exceeds_unreduced(ctx, b_0)?,
-            &self.reduce_if_limb_values_exceeds_unreduced(ctx, b_1)?,
-        );
-        self.sub_sub_generic(ctx, a, b_0, b_1)
+        self.span(ctx, "sub_sub", |ctx| {
+            let (a, b_0, b_1) = (
+                &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?,
+                &self.reduce_if_limb_values_exceeds_unreduced(ctx, b_0)?,
+                &self.reduce_if_limb_values_exceeds_unreduced(ctx, b_1)?,
+            );
+            let c = self.sub_sub_generic(ctx, a, b_0, b_1)?;
+            self.apply_policy(ctx, c)
+        })
     }
 
     fn neg(
@@ -223,8 +352,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let a = &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?;
-        self.neg_generic(ctx, a)
+        self.span(ctx, "neg", |ctx| {
+            let a = &self.reduce_if_limb_values_exceeds_unreduced(ctx, a)?;
+            let c = self.neg_generic(ctx, a)?;
+            self.apply_policy(ctx, c)
+        })
     }
 
     fn mul(
@@ -233,15 +365,17 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let (a, b) = (
-            &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?,
-            &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?,
-        );
-        let (a, b) = (
-            &self.reduce_if_max_operand_value_exceeds(ctx, a)?,
-            &self.reduce_if_max_operand_value_exceeds(ctx, b)?,
-        );
-        self.mul_generic(ctx, a, b)
+        self.span(ctx, "mul", |ctx| {
+            let (a, b) = (
+                &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?,
+                &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?,
+            );
+            let (a, b) = (
+                &self.reduce_if_max_operand_value_exceeds(ctx, a)?,
+                &self.reduce_if_max_operand_value_exceeds(ctx, b)?,
+            );
+            self.mul_generic(ctx, a, b)
+        })
     }
 
     fn mul_constant(
@@ -250,9 +384,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
-        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
-        self.mul_constant_generic(ctx, a, b)
+        self.span(ctx, "mul_constant", |ctx| {
+            let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+            let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+            self.mul_constant_generic(ctx, a, b)
+        })
     }
 
     fn mul_into_one(
@@ -261,15 +397,17 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
-        let (a, b) = (
-            &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?,
-            &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?,
-        );
-        let (a, b) = (
-            &self.reduce_if_max_operand_value_exceeds(ctx, a)?,
-            &self.reduce_if_max_operand_value_exceeds(ctx, b)?,
-        );
-        self.mul_into_one_generic(ctx, a, b)
+        self.span(ctx, "mul_into_one", |ctx| {
+            let (a, b) = (
+                &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?,
+                &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?,
+            );
+            let (a, b) = (
+                &self.reduce_if_max_operand_value_exceeds(ctx, a)?,
+                &self.reduce_if_max_operand_value_exceeds(ctx, b)?,
+            );
+            self.mul_into_one_generic(ctx, a, b)
+        })
     }
 
     fn square(
@@ -277,9 +415,24 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
-        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
-        self.square_generic(ctx, a)
+        self.span(ctx, "square", |ctx| {
+            let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+            let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+            self.square_generic(ctx, a)
+        })
+    }
+
+    fn div_by_small_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: u64,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.span(ctx, "div_by_small_constant", |ctx| {
+            let c_inv = self.rns.small_constant_inverse(c).ok_or(Error::Synthesis)?;
+            let c_inv = Integer::from_fe(c_inv, self.rns());
+            self.mul_constant(ctx, a, &c_inv)
+        })
     }
 
     fn div(
@@ -294,15 +447,17 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ),
         Error,
     > {
-        let (a, b) = (
-            &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?,
-            &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?,
-        );
-        let (a, b) = (
-            &self.reduce_if_max_operand_value_exceeds(ctx, a)?,
-            &self.reduce_if_max_operand_value_exceeds(ctx, b)?,
-        );
-        self.div_generic(ctx, a, b)
+        self.span(ctx, "div", |ctx| {
+            let (a, b) = (
+                &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?,
+                &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?,
+            );
+            let (a, b) = (
+                &self.reduce_if_max_operand_value_exceeds(ctx, a)?,
+                &self.reduce_if_max_operand_value_exceeds(ctx, b)?,
+            );
+            self.div_generic(ctx, a, b)
+        })
     }
 
     fn div_incomplete(
@@ -311,15 +466,17 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let (a, b) = (
-            &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?,
-            &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?,
-        );
-        let (a, b) = (
-            &self.reduce_if_max_operand_value_exceeds(ctx, a)?,
-            &self.reduce_if_max_operand_value_exceeds(ctx, b)?,
-        );
-        self.div_incomplete_generic(ctx, a, b)
+        self.span(ctx, "div_incomplete", |ctx| {
+            let (a, b) = (
+                &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?,
+                &self.reduce_if_limb_values_exceeds_reduced(ctx, b)?,
+            );
+            let (a, b) = (
+                &self.reduce_if_max_operand_value_exceeds(ctx, a)?,
+                &self.reduce_if_max_operand_value_exceeds(ctx, b)?,
+            );
+            self.div_incomplete_generic(ctx, a, b)
+        })
     }
 
     fn invert(
@@ -333,9 +490,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ),
         Error,
     > {
-        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
-        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
-        self.invert_generic(ctx, a)
+        self.span(ctx, "invert", |ctx| {
+            let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+            let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+            self.invert_generic(ctx, a)
+        })
     }
 
     fn invert_incomplete(
@@ -343,9 +502,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
-        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
-        self.invert_incomplete_generic(ctx, a)
+        self.span(ctx, "invert_incomplete", |ctx| {
+            let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+            let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+            self.invert_incomplete_generic(ctx, a)
+        })
     }
 
     fn reduce(
@@ -353,7 +514,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        self.reduce_generic(ctx, a)
+        self.span(ctx, "reduce", |ctx| self.reduce_generic(ctx, a))
     }
 
     fn assert_equal(
@@ -362,9 +523,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
-        let c = &self.sub(ctx, a, b)?;
-        self.assert_zero_generic(ctx, c)?;
-        Ok(())
+        self.span(ctx, "assert_equal", |ctx| {
+            let c = &self.sub(ctx, a, b)?;
+            self.assert_zero_generic(ctx, c)?;
+            Ok(())
+        })
     }
 
     fn assert_strict_equal(
@@ -373,11 +536,16 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
-        let main_gate = self.main_gate();
-        for idx in 0..NUMBER_OF_LIMBS {
-            main_gate.assert_equal(ctx, a.limb(idx), b.limb(idx))?;
-        }
-        Ok(())
+        self.span(ctx, "assert_strict_equal", |ctx| {
+            let main_gate = self.main_gate();
+            for idx in 0..NUMBER_OF_LIMBS {
+                main_gate.assert_equal(ctx, a.limb(idx), b.limb(idx))?;
+                self.record_relation("assert_strict_equal", "limb", |r| {
+                    format!("{} - {}", r.variable(a.limb(idx)), r.variable(b.limb(idx)))
+                });
+            }
+            Ok(())
+        })
     }
 
     fn assert_not_equal(
@@ -386,9 +554,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
-        let c = &self.sub(ctx, a, b)?;
-        self.assert_not_zero_generic(ctx, c)?;
-        Ok(())
+        self.span(ctx, "assert_not_equal", |ctx| {
+            let c = &self.sub(ctx, a, b)?;
+            self.assert_not_zero_generic(ctx, c)?;
+            Ok(())
+        })
     }
 
     fn assert_not_zero(
@@ -396,10 +566,12 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
-        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
-        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
-        self.assert_not_zero_generic(ctx, a)?;
-        Ok(())
+        self.span(ctx, "assert_not_zero", |ctx| {
+            let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+            let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+            self.assert_not_zero_generic(ctx, a)?;
+            Ok(())
+        })
     }
 
     fn assert_zero(
@@ -407,13 +579,16 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
-        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
-        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
-        let main_gate = self.main_gate();
-        for limb in a.limbs() {
-            main_gate.assert_zero(ctx, &limb.into())?;
-        }
-        Ok(())
+        self.span(ctx, "assert_zero", |ctx| {
+            let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+            let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+            let main_gate = self.main_gate();
+            for limb in a.limbs() {
+                main_gate.assert_zero(ctx, &limb.into())?;
+            }
+            self.record_strict_limbs("assert_zero", a, 0);
+            Ok(())
+        })
     }
 
     fn assert_strict_zero(
@@ -421,11 +596,14 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
-        let main_gate = self.main_gate();
-        for limb in a.limbs() {
-            main_gate.assert_zero(ctx, &limb.into())?;
-        }
-        Ok(())
+        self.span(ctx, "assert_strict_zero", |ctx| {
+            let main_gate = self.main_gate();
+            for limb in a.limbs() {
+                main_gate.assert_zero(ctx, &limb.into())?;
+            }
+            self.record_strict_limbs("assert_strict_zero", a, 0);
+            Ok(())
+        })
     }
 
     fn assert_strict_one(
@@ -433,11 +611,18 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
-        let main_gate = self.main_gate();
-        for i in 1..NUMBER_OF_LIMBS {
-            main_gate.assert_zero(ctx, a.limb(i))?;
-        }
-        main_gate.assert_one(ctx, a.limb(0))
+        self.span(ctx, "assert_strict_one", |ctx| {
+            let main_gate = self.main_gate();
+            for i in 1..NUMBER_OF_LIMBS {
+                main_gate.assert_zero(ctx, a.limb(i))?;
+            }
+            main_gate.assert_one(ctx, a.limb(0))?;
+            self.record_strict_limbs("assert_strict_one", a, 1);
+            self.record_relation("assert_strict_one", "limb", |r| {
+                format!("{} - 1", r.variable(a.limb(0)))
+            });
+            Ok(())
+        })
     }
 
     fn assert_strict_bit(
@@ -445,11 +630,19 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
-        let main_gate = self.main_gate();
-        for i in 1..NUMBER_OF_LIMBS {
-            main_gate.assert_zero(ctx, a.limb(i))?;
-        }
-        main_gate.assert_bit(ctx, a.limb(0))
+        self.span(ctx, "assert_strict_bit", |ctx| {
+            let main_gate = self.main_gate();
+            for i in 1..NUMBER_OF_LIMBS {
+                main_gate.assert_zero(ctx, a.limb(i))?;
+            }
+            main_gate.assert_bit(ctx, a.limb(0))?;
+            self.record_strict_limbs("assert_strict_bit", a, 1);
+            self.record_relation("assert_strict_bit", "limb", |r| {
+                let bit = r.variable(a.limb(0));
+                format!("{bit} * {bit} - {bit}")
+            });
+            Ok(())
+        })
     }
 
     fn select(
@@ -459,24 +652,26 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         cond: &AssignedCondition<N>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let main_gate = self.main_gate();
+        self.span(ctx, "select", |ctx| {
+            let main_gate = self.main_gate();
 
-        let mut limbs: Vec<AssignedLimb<N>> = Vec::with_capacity(NUMBER_OF_LIMBS);
-        for i in 0..NUMBER_OF_LIMBS {
-            let res = main_gate.select(ctx, a.limb(i), b.limb(i), cond)?;
+            let mut limbs: Vec<AssignedLimb<N>> = Vec::with_capacity(NUMBER_OF_LIMBS);
+            for i in 0..NUMBER_OF_LIMBS {
+                let res = main_gate.select(ctx, a.limb(i), b.limb(i), cond)?;
 
-            let max_val = if a.limbs[i].max_val > b.limbs[i].max_val {
-                a.limbs[i].max_val.clone()
-            } else {
-                b.limbs[i].max_val.clone()
-            };
+                let max_val = if a.limbs[i].max_val > b.limbs[i].max_val {
+                    a.limbs[i].max_val.clone()
+                } else {
+                    b.limbs[i].max_val.clone()
+                };
 
-            limbs.push(AssignedLimb::from(res, max_val));
-        }
+                limbs.push(AssignedLimb::from(res, max_val));
+            }
 
-        let native_value = main_gate.select(ctx, a.native(), b.native(), cond)?;
+            let native_value = main_gate.select(ctx, a.native(), b.native(), cond)?;
 
-        Ok(self.new_assigned_integer(&limbs.try_into().unwrap(), native_value))
+            Ok(self.new_assigned_integer(&limbs.try_into().unwrap(), native_value))
+        })
     }
 
     fn select_or_assign(
@@ -486,22 +681,24 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         cond: &AssignedCondition<N>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
-        let main_gate = self.main_gate();
+        self.span(ctx, "select_or_assign", |ctx| {
+            let main_gate = self.main_gate();
 
-        let mut limbs: Vec<AssignedLimb<N>> = Vec::with_capacity(NUMBER_OF_LIMBS);
-        for i in 0..NUMBER_OF_LIMBS {
-            let b_limb = b.limb(i);
+            let mut limbs: Vec<AssignedLimb<N>> = Vec::with_capacity(NUMBER_OF_LIMBS);
+            for i in 0..NUMBER_OF_LIMBS {
+                let b_limb = b.limb(i);
 
-            let res = main_gate.select_or_assign(ctx, a.limb(i), b_limb.fe(), cond)?;
+                let res = main_gate.select_or_assign(ctx, a.limb(i), b_limb.fe(), cond)?;
 
-            // here we assume given constant is always in field
-            let max_val = a.limbs[i].max_val();
-            limbs.push(AssignedLimb::from(res, max_val));
-        }
+                // here we assume given constant is always in field
+                let max_val = a.limbs[i].max_val();
+                limbs.push(AssignedLimb::from(res, max_val));
+            }
 
-        let native_value = main_gate.select_or_assign(ctx, a.native(), b.native(), cond)?;
+            let native_value = main_gate.select_or_assign(ctx, a.native(), b.native(), cond)?;
 
-        Ok(self.new_assigned_integer(&limbs.try_into().unwrap(), native_value))
+            Ok(self.new_assigned_integer(&limbs.try_into().unwrap(), native_value))
+        })
     }
 
     fn assert_in_field(
@@ -509,9 +706,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<(), Error> {
-        let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
-        let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
-        self.assert_in_field_generic(ctx, a)
+        self.span(ctx, "assert_in_field", |ctx| {
+            let a = &self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+            let a = &self.reduce_if_max_operand_value_exceeds(ctx, a)?;
+            self.assert_in_field_generic(ctx, a)
+        })
     }
 
     fn sign(
@@ -519,46 +718,272 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedCondition<N>, Error> {
-        self.assert_in_field(ctx, a)?;
-
-        // Assignes new value equals to `1` if least significant bit of `a` is `1` or assigns
-        // `0` if lsb of `a` is `0`.
-        let w: Value<(N, N)> = a.limb(0).value().map(|value| {
-            use maingate::{big_to_fe, fe_to_big};
-            use num_bigint::BigUint;
-            use num_traits::{One, Zero};
-            let value = &fe_to_big(*value);
-            let half = big_to_fe(value / 2usize);
-            let sign = ((value & BigUint::one() != BigUint::zero()) as u64).into();
-            (sign, half)
-        });
+        self.span(ctx, "sign", |ctx| {
+            self.assert_in_field(ctx, a)?;
+
+            // Assignes new value equals to `1` if least significant bit of `a` is `1` or assigns
+            // `0` if lsb of `a` is `0`.
+            let w: Value<(N, N)> = a.limb(0).value().map(|value| {
+                use maingate::{big_to_fe, fe_to_big};
+                use num_bigint::BigUint;
+                use num_traits::{One, Zero};
+                let value = &fe_to_big(*value);
+                let half = big_to_fe(value / 2usize);
+                let sign = ((value & BigUint::one() != BigUint::zero()) as u64).into();
+                (sign, half)
+            });
+
+            let sign = self.main_gate.assign_bit(ctx, w.map(|w| w.0))?;
+
+            let half_a = self
+                .main_gate
+                .apply(
+                    ctx,
+                    [
+                        Term::Unassigned(w.map(|w| w.1), N::from(2)),
+                        Term::Assigned(&sign, N::ONE),
+                        Term::Assigned(a.limb(0), -N::ONE),
+                    ],
+                    N::ZERO,
+                    CombinationOptionCommon::OneLinerAdd.into(),
+                )?
+                .swap_remove(0);
+
+            // Enforce half_a in [0, (LIMB_MAX_VAL / 2) )
+            let assigned = self.range_chip.decompose(
+                ctx,
+                half_a.value_field().evaluate(),
+                Self::sublimb_bit_len(),
+                BIT_LEN_LIMB - 1,
+            )?;
+            self.main_gate.assert_equal(ctx, &assigned.0, &half_a)?;
 
-        let sign = self.main_gate.assign_bit(ctx, w.map(|w| w.0))?;
+            Ok(sign)
+        })
+    }
 
-        let half_a = self
-            .main_gate
-            .apply(
-                ctx,
-                [
-                    Term::Unassigned(w.map(|w| w.1), N::from(2)),
-                    Term::Assigned(&sign, N::ONE),
-                    Term::Assigned(a.limb(0), -N::ONE),
-                ],
-                N::ZERO,
-                CombinationOptionCommon::OneLinerAdd.into(),
-            )?
-            .swap_remove(0);
-
-        // Enforce half_a in [0, (LIMB_MAX_VAL / 2) )
-        let assigned = self.range_chip.decompose(
-            ctx,
-            half_a.value_field().evaluate(),
-            Self::sublimb_bit_len(),
-            BIT_LEN_LIMB - 1,
-        )?;
-        self.main_gate.assert_equal(ctx, &assigned.0, &half_a)?;
-
-        Ok(sign)
+    fn rlc(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        values: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        challenge: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.span(ctx, "rlc", |ctx| self.rlc_generic(ctx, values, challenge))
+    }
+
+    fn reduce_mod_assigned(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.span(ctx, "reduce_mod_assigned", |ctx| {
+            self.reduce_mod_assigned_generic(ctx, a, p)
+        })
+    }
+
+    fn decompress_x(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        x: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        sign: &AssignedCondition<N>,
+        b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.span(ctx, "decompress_x", |ctx| {
+            self.decompress_x_generic(ctx, x, sign, b)
+        })
+    }
+
+    fn gcd(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.span(ctx, "gcd", |ctx| self.gcd_generic(ctx, a, b))
+    }
+
+    fn assert_quadratic_root(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        x: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        self.span(ctx, "assert_quadratic_root", |ctx| {
+            // x^2 + b*x + c = 0
+            let x_square = &self.square(ctx, x)?;
+            let bx = &self.mul_constant(ctx, x, b)?;
+            let result = &self.add(ctx, x_square, bx)?;
+            let result = &self.add_constant(ctx, result, c)?;
+            self.assert_zero(ctx, result)
+        })
+    }
+
+    fn open_commitment(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: UnassignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        blinding: Value<N>,
+        commitment_instance: usize,
+        committer: &dyn CommitInstructions<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.span(ctx, "open_commitment", |ctx| {
+            self.open_commitment_generic(ctx, value, blinding, commitment_instance, committer)
+        })
+    }
+
+    fn limb_sum(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedValue<N>, Error> {
+        self.span(ctx, "limb_sum", |ctx| {
+            let terms = a
+                .limbs()
+                .iter()
+                .map(|limb| Term::assigned_to_add(limb.as_ref()))
+                .collect::<Vec<Term<N>>>();
+            self.main_gate().compose(ctx, &terms, N::ZERO)
+        })
+    }
+
+    fn reconstruct_secret(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        shares: &[(
+            Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.span(ctx, "reconstruct_secret", |ctx| {
+            self.reconstruct_secret_generic(ctx, shares)
+        })
+    }
+
+    fn square_tower(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        k: usize,
+    ) -> Result<Vec<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>, Error> {
+        self.span(ctx, "square_tower", |ctx| {
+            let mut tower = Vec::with_capacity(k + 1);
+            tower.push(a.clone());
+            let mut last = a.clone();
+            for _ in 0..k {
+                last = self.square(ctx, &last)?;
+                tower.push(last.clone());
+            }
+            Ok(tower)
+        })
+    }
+
+    fn assert_recurrence(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        prev2: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        prev1: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cur: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        a: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        self.span(ctx, "assert_recurrence", |ctx| {
+            // cur = a * prev1 + b * prev2
+            let a_prev1 = &self.mul_constant(ctx, prev1, a)?;
+            let b_prev2 = &self.mul_constant(ctx, prev2, b)?;
+            let expected = &self.add(ctx, a_prev1, b_prev2)?;
+            self.assert_equal(ctx, expected, cur)
+        })
+    }
+
+    fn det2(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        d: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.span(ctx, "det2", |ctx| {
+            // Inputs are kept in remainder range so that `a * d - b * c` shifted
+            // by an aux is reduced with a single quotient
+            let [a, b, c, d] =
+                [a, b, c, d].map(|e| self.reduce_if_max_remainder_value_exceeds(ctx, e));
+            self.det2_generic(ctx, &a?, &b?, &c?, &d?)
+        })
+    }
+
+    fn cbrt(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    > {
+        self.span(ctx, "cbrt", |ctx| self.cbrt_generic(ctx, a))
+    }
+
+    fn assign_small(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: Value<N>,
+        bit_len: usize,
+    ) -> Result<SmallInteger<N>, Error> {
+        self.span(ctx, "assign_small", |ctx| {
+            self.assign_small_generic(ctx, value, bit_len)
+        })
+    }
+
+    fn add_small(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &SmallInteger<N>,
+        b: &SmallInteger<N>,
+    ) -> Result<SmallInteger<N>, Error> {
+        self.span(ctx, "add_small", |ctx| self.add_small_generic(ctx, a, b))
+    }
+
+    fn mul_small(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &SmallInteger<N>,
+        b: &SmallInteger<N>,
+    ) -> Result<SmallInteger<N>, Error> {
+        self.span(ctx, "mul_small", |ctx| self.mul_small_generic(ctx, a, b))
+    }
+
+    fn promote(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &SmallInteger<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.span(ctx, "promote", |ctx| self.promote_generic(ctx, a))
+    }
+
+    fn pack(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedValue<N>>, Error> {
+        self.span(ctx, "pack", |ctx| self.pack_generic(ctx, a))
+    }
+
+    fn assert_merkle_member(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        path: &[(AssignedValue<N>, AssignedCondition<N>)],
+        root: &AssignedValue<N>,
+        hasher: &dyn HashInstructions<N>,
+    ) -> Result<(), Error> {
+        self.span(ctx, "assert_merkle_member", |ctx| {
+            self.assert_merkle_member_generic(ctx, a, path, root, hasher)
+        })
     }
 }
 
@@ -571,9 +996,113 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             range_chip: RangeChip::new(config.range_config),
             main_gate: MainGate::new(config.main_gate_config),
             rns,
+            relations: None,
+            policy: ReductionPolicy::default(),
+            reductions: Rc::new(Cell::new(0)),
+            span_callback: None,
+            span_depth: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Attaches a callback that is called with operation name, start offset
+    /// and end offset for each outermost operation of this chip. Offsets are
+    /// relative to the region and the end offset is exclusive.
+    pub fn with_span_callback(mut self, callback: impl Fn(&str, usize, usize) + 'static) -> Self {
+        self.span_callback = Some(SpanCallback(Rc::new(callback)));
+        self
+    }
+
+    /// Runs an operation and emits its span if a callback is attached and
+    /// the operation is not nested in another one. Operations that don't
+    /// record their relations are reported to the attached recorder.
+    fn span<'a, T>(
+        &self,
+        ctx: &mut RegionCtx<'a, N>,
+        op: &str,
+        f: impl FnOnce(&mut RegionCtx<'a, N>) -> Result<T, Error>,
+    ) -> Result<T, Error> {
+        if let Some(recorder) = &self.relations {
+            if !RECORDED_OPS.contains(&op) {
+                recorder.mark_unrecorded(op);
+            }
+        }
+        let callback = match &self.span_callback {
+            Some(callback) => callback,
+            None => return f(ctx),
+        };
+        let start = ctx.offset();
+        self.span_depth.set(self.span_depth.get() + 1);
+        let result = f(ctx);
+        self.span_depth.set(self.span_depth.get() - 1);
+        if self.span_depth.get() == 0 {
+            (callback.0)(op, start, ctx.offset());
+        }
+        result
+    }
+
+    /// Returns a view of this chip that applies the given
+    /// [`ReductionPolicy`]
+    pub fn with_policy(
+        &self,
+        policy: ReductionPolicy,
+    ) -> IntegerChipView<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        IntegerChipView(IntegerChip {
+            policy,
+            ..self.clone()
+        })
+    }
+
+    /// Getter for [`ReductionPolicy`]
+    pub fn policy(&self) -> ReductionPolicy {
+        self.policy
+    }
+
+    /// Returns number of reductions applied by this chip and its views
+    pub fn reduction_count(&self) -> usize {
+        self.reductions.get()
+    }
+
+    /// Attaches a [`RelationRecorder`] that collects algebraic relations
+    /// enforced by the operations of this chip
+    pub fn with_relation_recorder(mut self, recorder: RelationRecorder) -> Self {
+        self.relations = Some(recorder);
+        self
+    }
+
+    /// Records a relation if a recorder is attached. Expression is built
+    /// lazily with the recorder naming the variables to keep the default path
+    /// free of formatting cost.
+    pub(crate) fn record_relation(
+        &self,
+        op: &str,
+        kind: &str,
+        expression: impl FnOnce(&RelationRecorder) -> String,
+    ) {
+        if let Some(recorder) = &self.relations {
+            let expression = expression(recorder);
+            recorder.record(op, kind, expression);
+        }
+    }
+
+    /// Records that limbs of `a` starting from `from` are constrained to zero
+    fn record_strict_limbs(
+        &self,
+        op: &str,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        from: usize,
+    ) {
+        for limb in a.limbs().iter().skip(from) {
+            self.record_relation(op, "limb", |r| r.variable(limb.as_ref()));
         }
     }
 
+    /// Records that `value` is range checked to `bit_len` bits
+    pub(crate) fn record_range(&self, op: &str, value: &AssignedValue<N>, bit_len: usize) {
+        self.record_relation(op, "range", |r| {
+            format!("{} < 2^{bit_len}", r.variable(value))
+        });
+    }
+
     /// Getter for [`RangeChip`]
     pub fn range_chip(&self) -> &RangeChip<N> {
         &self.range_chip
@@ -592,19 +1121,27 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
 
 #[cfg(test)]
 mod tests {
-    use super::{IntegerChip, IntegerConfig, IntegerInstructions, Range};
-    use crate::rns::{Common, Integer, Rns};
-    use crate::{PrimeField, UnassignedInteger};
+    use super::cbrt::{cube_root, cubic_non_residue};
+    use super::{
+        CommitInstructions, HashInstructions, IntegerChip, IntegerConfig, IntegerInstructions,
+        Range, ReductionPolicy,
+    };
+    use crate::relations::RelationRecorder;
+    use crate::rns::{Common, Integer, MaybeReduced, ReduceBackend, Rns};
+    use crate::{AssignedLimb, PrimeField, UnassignedInteger};
     use halo2::circuit::{Layouter, SimpleFloorPlanner, Value};
     use halo2::plonk::{Circuit, ConstraintSystem, Error};
-    use maingate::mock_prover_verify;
     use maingate::{
-        big_to_fe, decompose_big, fe_to_big, halo2, AssignedCondition, MainGate, MainGateConfig,
-        MainGateInstructions, RangeChip, RangeConfig, RangeInstructions, RegionCtx,
+        big_to_fe, compose, decompose_big, fe_to_big, halo2, AssignedCondition, AssignedValue,
+        MainGate, MainGateConfig, MainGateInstructions, RangeChip, RangeConfig, RangeInstructions,
+        RegionCtx,
     };
+    use maingate::{mock_prover_verify, mock_prover_verify_fails};
     use num_bigint::{BigUint as big_uint, RandBigInt};
-    use num_traits::Zero;
+    use num_integer::Integer as _;
+    use num_traits::{One, Zero};
     use rand_core::OsRng;
+    use std::cell::RefCell;
     use std::rc::Rc;
 
     const NUMBER_OF_LIMBS: usize = 4;
@@ -1557,39 +2094,1474 @@ mod tests {
         }
     );
 
-    macro_rules! test_circuit_runner {
-        (
-            $circuit:ident, $([$wrong_field:ident, $native_field:ident, $bit_len_limb:expr]),*
-        ) => {
-            $(
-                let (rns, _):(Rns<$wrong_field, $native_field, NUMBER_OF_LIMBS, $bit_len_limb>, u32) = setup();
+    impl_circuit!(
+        TestCircuitRlc,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
 
-                let circuit = $circuit::<$wrong_field, $native_field, $bit_len_limb> { rns: Rc::new(rns) };
-            let instance = vec![vec![]];
-            mock_prover_verify(&circuit, instance);
-            )*
-        };
-    }
+                    let challenge = t.rand_in_field();
+                    let values: Vec<Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> =
+                        (0..5).map(|_| t.rand_in_field()).collect();
+
+                    // sum_i values_i * challenge^i
+                    let modulus = &self.rns.wrong_modulus;
+                    let mut power = big_uint::one();
+                    let mut expected = big_uint::zero();
+                    for value in values.iter() {
+                        expected = (expected + value.value() * &power) % modulus;
+                        power = (power * challenge.value()) % modulus;
+                    }
+                    let expected = t.new_from_big(expected);
+
+                    let challenge =
+                        integer_chip.assign_integer(ctx, challenge.into(), Range::Remainder)?;
+                    let values = values
+                        .into_iter()
+                        .map(|value| {
+                            integer_chip.assign_integer(ctx, value.into(), Range::Remainder)
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let expected =
+                        integer_chip.assign_integer(ctx, expected.into(), Range::Remainder)?;
+
+                    let rlc = integer_chip.rlc(ctx, &values, &challenge)?;
+                    integer_chip.assert_equal(ctx, &rlc, &expected)?;
+
+                    // single value is returned as is
+                    let rlc = integer_chip.rlc(ctx, &values[..1], &challenge)?;
+                    integer_chip.assert_strict_equal(ctx, &rlc, &values[0])?;
+
+                    // empty input is zero
+                    let rlc = integer_chip.rlc(ctx, &[], &challenge)?;
+                    integer_chip.assert_strict_zero(ctx, &rlc)?;
 
-    macro_rules! test_circuit {
-        (
-            $circuit:ident
-        ) => {
-            use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
-            use crate::curves::pasta::{Fp as PastaFp, Fq as PastaFq};
-            use crate::curves::secp256k1::{Fp as Secp256k1Base, Fq as Secp256k1Scalar};
-            test_circuit_runner!(
-                $circuit,
-                [PastaFp, PastaFq, 68],
-                [PastaFq, PastaFp, 68],
-                [BnBase, BnScalar, 68],
-                [BnScalar, BnScalar, 68],
-                [Secp256k1Base, BnScalar, 68],
-                [Secp256k1Base, PastaFp, 68],
-                [Secp256k1Base, PastaFq, 68],
-                [Secp256k1Scalar, BnScalar, 68],
-                [Secp256k1Scalar, PastaFp, 68],
-                [Secp256k1Scalar, PastaFq, 68]
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitReduceModAssigned,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let wrong_modulus_bit_len = self.rns.wrong_modulus.bits();
+                    for bit_len in [
+                        1,
+                        BIT_LEN_LIMB as u64,
+                        wrong_modulus_bit_len / 2,
+                        wrong_modulus_bit_len,
+                        self.rns.max_operand.bits(),
+                    ] {
+                        let a = t.rand_in_operand_range();
+                        let p = OsRng.gen_biguint(bit_len) % &self.rns.max_operand + 1usize;
+                        let p = t.new_from_big(p);
+                        let r = t.new_from_big(a.value() % p.value());
+
+                        let a = integer_chip.assign_integer(ctx, a.into(), Range::Operand)?;
+                        let p = integer_chip.assign_integer(ctx, p.into(), Range::Operand)?;
+                        let r_0 = integer_chip.assign_integer(ctx, r.into(), Range::Operand)?;
+                        let r_1 = integer_chip.reduce_mod_assigned(ctx, &a, &p)?;
+                        integer_chip.assert_strict_equal(ctx, &r_0, &r_1)?;
+                    }
+
+                    // a < p
+                    let a = t.rand_in_field();
+                    let p = t.new_from_big(a.value() + 1usize);
+                    let a = integer_chip.assign_integer(ctx, a.into(), Range::Operand)?;
+                    let p = integer_chip.assign_integer(ctx, p.into(), Range::Operand)?;
+                    let r = integer_chip.reduce_mod_assigned(ctx, &a, &p)?;
+                    integer_chip.assert_strict_equal(ctx, &a, &r)?;
+
+                    // a = p
+                    let r = integer_chip.reduce_mod_assigned(ctx, &p, &p)?;
+                    integer_chip.assert_strict_zero(ctx, &r)?;
+
+                    // Lazily added input and modulus with overflowing limbs
+                    let (a_0, a_1) = (t.rand_in_remainder_range(), t.rand_in_remainder_range());
+                    let (p_0, p_1) = (t.rand_in_remainder_range(), t.rand_in_remainder_range());
+                    let r =
+                        t.new_from_big((a_0.value() + a_1.value()) % (p_0.value() + p_1.value()));
+                    let a_0 = integer_chip.assign_integer(ctx, a_0.into(), Range::Remainder)?;
+                    let a_1 = integer_chip.assign_integer(ctx, a_1.into(), Range::Remainder)?;
+                    let p_0 = integer_chip.assign_integer(ctx, p_0.into(), Range::Remainder)?;
+                    let p_1 = integer_chip.assign_integer(ctx, p_1.into(), Range::Remainder)?;
+                    let a = integer_chip.add(ctx, &a_0, &a_1)?;
+                    let p = integer_chip.add(ctx, &p_0, &p_1)?;
+                    let r_0 = integer_chip.assign_integer(ctx, r.into(), Range::Operand)?;
+                    let r_1 = integer_chip.reduce_mod_assigned(ctx, &a, &p)?;
+                    integer_chip.assert_strict_equal(ctx, &r_0, &r_1)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitReduceModAssignedZeroModulus,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_field();
+                    let a = integer_chip.assign_integer(ctx, a.into(), Range::Operand)?;
+                    let p = integer_chip.assign_integer(ctx, t.zero().into(), Range::Operand)?;
+                    integer_chip.reduce_mod_assigned(ctx, &a, &p)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitDecompress,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // y^2 = x^3 + 7
+                    let b = W::from(7);
+                    let b_integer = t.new_from_big(fe_to_big(b));
+                    for _ in 0..2 {
+                        let (x, y) = loop {
+                            let x = W::random(OsRng);
+                            let y: Option<W> = (x.square() * x + b).sqrt().into();
+                            if let Some(y) = y {
+                                break (x, y);
+                            }
+                        };
+                        let x = t.new_from_big(fe_to_big(x));
+                        let x = integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
+
+                        for sign in [false, true] {
+                            let expected = if bool::from(y.is_odd()) == sign {
+                                y
+                            } else {
+                                -y
+                            };
+                            let expected = t.new_from_big(fe_to_big(expected));
+                            let expected = integer_chip.assign_integer(
+                                ctx,
+                                expected.into(),
+                                Range::Remainder,
+                            )?;
+
+                            let sign = if sign { N::ONE } else { N::ZERO };
+                            let sign = main_gate.assign_bit(ctx, Value::known(sign))?;
+                            let y = integer_chip.decompress_x(ctx, &x, &sign, &b_integer)?;
+                            integer_chip.assert_strict_equal(ctx, &y, &expected)?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitDecompressNonResidue,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // x is not on y^2 = x^3 + 7
+                    let b = W::from(7);
+                    let x = loop {
+                        let x = W::random(OsRng);
+                        if bool::from((x.square() * x + b).sqrt().is_none()) {
+                            break x;
+                        }
+                    };
+                    let x = t.new_from_big(fe_to_big(x));
+                    let x = integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
+                    let sign = main_gate.assign_bit(ctx, Value::known(N::ZERO))?;
+                    let b = t.new_from_big(fe_to_big(b));
+                    integer_chip.decompress_x(ctx, &x, &sign, &b)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitLimbOverflow,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_remainder_range();
+                    let a = integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let limb =
+                        AssignedLimb::from(a.limb(0).clone(), big_uint::one() << (N::NUM_BITS - 1));
+                    limb.mul3();
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitDivBySmallConstant,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for c in [1u64, 2, 3, 7, 2, 3, 7, 255, u64::MAX] {
+                        let a = W::random(OsRng);
+                        let expected = a * W::from(c).invert().unwrap();
+                        let a = t.new_from_big(fe_to_big(a));
+                        let a = integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                        let expected = t.new_from_big(fe_to_big(expected));
+                        let expected =
+                            integer_chip.assign_integer(ctx, expected.into(), Range::Remainder)?;
+                        let c = &integer_chip.div_by_small_constant(ctx, &a, c)?;
+                        integer_chip.assert_equal(ctx, c, &expected)?;
+                    }
+                    let a = t.rand_in_remainder_range();
+                    let a = integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    assert!(integer_chip.div_by_small_constant(ctx, &a, 0).is_err());
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    #[cfg(feature = "checksum")]
+    impl_circuit!(
+        TestCircuitChecksum,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_operand_range();
+                    let a = integer_chip.assign_integer(ctx, a.into(), Range::Operand)?;
+                    assert!(a.checksum.is_some());
+                    integer_chip.assert_checksum(ctx, &a)?;
+
+                    let a = t.rand_in_field();
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    integer_chip.assert_checksum(ctx, a)?;
+
+                    // Checksums are carried through arithmetic
+                    let b = t.rand_in_remainder_range();
+                    let b = &integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+                    let sum = &integer_chip.add(ctx, a, b)?;
+                    let difference = &integer_chip.sub(ctx, a, b)?;
+                    let product = &integer_chip.mul(ctx, sum, difference)?;
+                    let square = &integer_chip.square(ctx, product)?;
+                    let reduced =
+                        &integer_chip.reduce(ctx, &integer_chip.add(ctx, square, sum)?)?;
+                    for c in [sum, difference, product, square, reduced] {
+                        assert!(c.checksum.is_some());
+                        integer_chip.assert_checksum(ctx, c)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    #[cfg(feature = "checksum")]
+    impl_circuit!(
+        TestCircuitChecksumBrokenWitness,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_field();
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = t.rand_in_field();
+                    let b = &integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+                    integer_chip.mul(ctx, a, b)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    /// Backend with a bug that shifts the remainder away from the quotient
+    #[cfg(feature = "checksum")]
+    #[derive(Debug)]
+    struct OffByOneBackend;
+
+    #[cfg(feature = "checksum")]
+    impl ReduceBackend for OffByOneBackend {
+        fn div_rem(&self, value: &big_uint, modulus: &big_uint) -> (big_uint, big_uint) {
+            let (quotient, remainder) = value.div_rem(modulus);
+            (quotient, remainder + 1usize)
+        }
+    }
+
+    impl_circuit!(
+        TestCircuitGcd,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // Inputs share a factor of `g` so the result is non trivial
+                    let bit_len = self.rns.wrong_modulus.bits() - 1;
+                    let g = OsRng.gen_biguint(64) + 2usize;
+                    let a = &g * OsRng.gen_biguint(bit_len - 65);
+                    let b = &g * OsRng.gen_biguint(bit_len - 65);
+
+                    let expected = t.new_from_big(a.gcd(&b));
+                    let a = t.new_from_big(a);
+                    let a = integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = t.new_from_big(b);
+                    let b = integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+                    let gcd = &integer_chip.gcd(ctx, &a, &b)?;
+                    let expected =
+                        integer_chip.assign_integer(ctx, expected.into(), Range::Remainder)?;
+                    integer_chip.assert_strict_equal(ctx, gcd, &expected)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitRelations,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let recorder = RelationRecorder::new();
+            let integer_chip = self
+                .integer_chip(config.clone())
+                .with_relation_recorder(recorder.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_remainder_range();
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = t.rand_in_remainder_range();
+                    let b = &integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+                    let e = t.rand_in_remainder_range();
+                    let e = &integer_chip.assign_integer(ctx, e.into(), Range::Remainder)?;
+                    let c = &integer_chip.mul(ctx, a, b)?;
+                    let d = &integer_chip.mul(ctx, c, e)?;
+
+                    let p = fe_to_big(self.rns.wrong_modulus_in_native_modulus);
+                    let [a_n, b_n, c_n, d_n, e_n] =
+                        [a, b, c, d, e].map(|x| recorder.variable(x.native()));
+                    let relations = recorder.relations();
+                    let native = relations
+                        .iter()
+                        .filter(|r| r.op == "mul" && r.kind == "native")
+                        .map(|r| r.expression.clone())
+                        .collect::<Vec<_>>();
+                    assert_eq!(native.len(), 2);
+
+                    // Second multiplication consumes the result of the first one
+                    assert!(native[0].starts_with(&format!("{a_n} * {b_n} - {p} * ")));
+                    assert!(native[0].ends_with(&format!(" - {c_n}")));
+                    assert!(native[1].starts_with(&format!("{c_n} * {e_n} - {p} * ")));
+                    assert!(native[1].ends_with(&format!(" - {d_n}")));
+
+                    // Binary crt and range checks that soundness depends on
+                    for kind in ["intermediate", "residue", "range"] {
+                        assert!(relations.iter().any(|r| r.op == "mul" && r.kind == kind));
+                    }
+                    for limb in c.limbs() {
+                        let limb = recorder.variable(limb.as_ref());
+                        let range = format!("{limb} < 2^");
+                        assert!(relations
+                            .iter()
+                            .any(|r| r.kind == "range" && r.expression.starts_with(&range)));
+                    }
+
+                    let json = recorder.to_json(&self.rns.native_modulus.to_string());
+                    assert!(json.contains(&format!("\"expression\":\"{}\"", native[0])));
+                    assert!(json.contains(&format!("{{\"name\":\"{c_n}\",\"cell\":")));
+
+                    // Additive and assertion gates are recorded as well
+                    let f = &integer_chip.sub(ctx, d, a)?;
+                    let g = &integer_chip.add(ctx, f, a)?;
+                    integer_chip.assert_equal(ctx, g, d)?;
+                    let relations = recorder.relations();
+                    for (op, kind) in [
+                        ("sub", "limb"),
+                        ("sub", "native"),
+                        ("add", "native"),
+                        ("assert_equal", "intermediate"),
+                        ("assert_equal", "residue"),
+                        ("assign_integer", "native"),
+                    ] {
+                        assert!(relations.iter().any(|r| r.op == op && r.kind == kind));
+                    }
+                    assert!(recorder.is_complete());
+                    let json = recorder.to_json(&self.rns.native_modulus.to_string());
+                    assert!(json.contains("\"complete\":true,\"unrecorded_ops\":[]"));
+
+                    // Operations that don't record their gates flag the export
+                    integer_chip.assert_not_zero(ctx, a)?;
+                    assert!(!recorder.is_complete());
+                    assert_eq!(recorder.unrecorded_ops(), vec!["assert_not_zero"]);
+                    let json = recorder.to_json(&self.rns.native_modulus.to_string());
+                    assert!(json
+                        .contains("\"complete\":false,\"unrecorded_ops\":[\"assert_not_zero\"]"));
+
+                    Ok(())
+                },
+            )?;
+
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitQuadraticRoot,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // (x - r_0) * (x - r_1) = x^2 - (r_0 + r_1) * x + r_0 * r_1
+                    let (r_0, r_1) = (W::random(OsRng), W::random(OsRng));
+                    let b = t.new_from_big(fe_to_big(-(r_0 + r_1)));
+                    let c = t.new_from_big(fe_to_big(r_0 * r_1));
+                    for root in [r_0, r_1] {
+                        let x = t.new_from_big(fe_to_big(root));
+                        let x = integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
+                        integer_chip.assert_quadratic_root(ctx, &x, &b, &c)?;
+                    }
+
+                    // x^2 - 4 has roots 2 and -2
+                    let b = t.zero();
+                    let c = t.new_from_big(fe_to_big(-W::from(4)));
+                    for root in [W::from(2), -W::from(2)] {
+                        let x = t.new_from_big(fe_to_big(root));
+                        let x = integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
+                        integer_chip.assert_quadratic_root(ctx, &x, &b, &c)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitQuadraticNonRoot,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // x^2 - 4 has roots 2 and -2
+                    let b = t.zero();
+                    let c = t.new_from_big(fe_to_big(-W::from(4)));
+                    let x = t.new_from_big(big_uint::from(3usize));
+                    let x = integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
+                    integer_chip.assert_quadratic_root(ctx, &x, &b, &c)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    #[derive(Clone, Debug)]
+    struct TestCircuitCommitment<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> {
+        rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+        value: big_uint,
+        blinding: N,
+    }
+
+    impl<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>
+        TestCircuitCommitment<W, N, BIT_LEN_LIMB>
+    {
+        fn commitment(&self) -> N {
+            let limbs_per_value = (N::NUM_BITS as usize - 1) / BIT_LEN_LIMB;
+            let limbs = Integer::from_big(self.value.clone(), Rc::clone(&self.rns)).limbs();
+            let mut inputs = limbs
+                .chunks(limbs_per_value)
+                .map(|limbs| {
+                    compose(
+                        limbs.iter().map(|limb| fe_to_big(*limb)).collect(),
+                        BIT_LEN_LIMB,
+                    )
+                })
+                .map(big_to_fe)
+                .collect::<Vec<N>>();
+            inputs.push(self.blinding);
+            TestHasher::native(&inputs)
+        }
+    }
+
+    impl<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize> Circuit<N>
+        for TestCircuitCommitment<W, N, BIT_LEN_LIMB>
+    {
+        type Config = TestCircuitConfig;
+        type FloorPlanner = SimpleFloorPlanner;
+        #[cfg(feature = "circuit-params")]
+        type Params = ();
+
+        fn without_witnesses(&self) -> Self {
+            unimplemented!();
+        }
+
+        fn configure(meta: &mut ConstraintSystem<N>) -> Self::Config {
+            TestCircuitConfig::new::<W, N, BIT_LEN_LIMB>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = IntegerChip::<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>::new(
+                config.integer_chip_config(),
+                Rc::clone(&self.rns),
+            );
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let value = Integer::from_big(self.value.clone(), Rc::clone(&self.rns));
+                    integer_chip.open_commitment(
+                        ctx,
+                        value.into(),
+                        Value::known(self.blinding),
+                        0,
+                        &TestHasher(integer_chip.main_gate().clone()),
+                    )?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    }
+
+    impl_circuit!(
+        TestCircuitLimbSum,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    for a in [
+                        t.rand_in_remainder_range(),
+                        t.rand_in_unreduced_range(),
+                        t.zero(),
+                    ] {
+                        let expected = a.limbs().into_iter().fold(N::ZERO, |acc, limb| acc + limb);
+                        let a = integer_chip.assign_integer(ctx, a.into(), Range::Unreduced)?;
+                        let sum = integer_chip.limb_sum(ctx, &a)?;
+                        main_gate.assert_equal_to_constant(ctx, &sum, expected)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitReductionPolicy,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_remainder_range();
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = t.rand_in_remainder_range();
+                    let b = &integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+
+                    let mut counts = vec![];
+                    for policy in [ReductionPolicy::Lazy, ReductionPolicy::Eager] {
+                        let chip = integer_chip.with_policy(policy);
+                        assert_eq!(chip.policy(), policy);
+                        let count = chip.reduction_count();
+
+                        let mut c = chip.add(ctx, a, b)?;
+                        for _ in 0..4 {
+                            c = chip.add(ctx, &c, b)?;
+                            c = chip.sub(ctx, &c, a)?;
+                        }
+                        let c = chip.mul(ctx, &c, a)?;
+
+                        // c = (5b - 3a) * a
+                        let expected = a.integer().zip(b.integer()).map(|(a, b)| {
+                            let (a, b): (W, W) = (big_to_fe(a.value()), big_to_fe(b.value()));
+                            t.new_from_big(fe_to_big((b * W::from(5) - a * W::from(3)) * a))
+                        });
+                        let expected =
+                            integer_chip.assign_integer(ctx, expected.into(), Range::Remainder)?;
+                        integer_chip.assert_equal(ctx, &c, &expected)?;
+
+                        counts.push(integer_chip.reduction_count() - count);
+                    }
+                    // Eager policy reduces the result of each of the nine additive
+                    // operations, while lazy policy lets limbs grow and reduces
+                    // only when mul needs its operand in range. Assertion is made
+                    // with the same lazy chip in both rounds, so it adds the same
+                    // number of reductions to both counts.
+                    assert!(counts[1] > counts[0]);
+                    assert_eq!(integer_chip.policy(), ReductionPolicy::Lazy);
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitReconstructSecret,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // f(x) = secret + c_1 * x + c_2 * x^2
+                    let secret = W::random(OsRng);
+                    let (c_1, c_2) = (W::random(OsRng), W::random(OsRng));
+                    let f = |x: W| secret + c_1 * x + c_2 * x.square();
+
+                    let xs = [W::from(1), W::from(2), W::from(3), W::random(OsRng)];
+                    let mut shares = vec![];
+                    for x in xs {
+                        let y = t.new_from_big(fe_to_big(f(x)));
+                        let y = integer_chip.assign_integer(ctx, y.into(), Range::Remainder)?;
+                        shares.push((t.new_from_big(fe_to_big(x)), y));
+                    }
+
+                    let expected = t.new_from_big(fe_to_big(secret));
+                    let expected =
+                        integer_chip.assign_integer(ctx, expected.into(), Range::Remainder)?;
+                    // Degree two polynomial is determined by any three shares
+                    for shares in [&shares[..], &shares[1..]] {
+                        let reconstructed = integer_chip.reconstruct_secret(ctx, shares)?;
+                        integer_chip.assert_equal(ctx, &reconstructed, &expected)?;
+                    }
+
+                    // Duplicate evaluation point
+                    let duplicated = [shares[0].clone(), shares[0].clone(), shares[1].clone()];
+                    assert!(integer_chip.reconstruct_secret(ctx, &duplicated).is_err());
+                    assert!(integer_chip.reconstruct_secret(ctx, &[]).is_err());
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitSquareTower,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let k = 5;
+                    let a = W::random(OsRng);
+                    let a_assigned = t.new_from_big(fe_to_big(a));
+                    let a_assigned =
+                        integer_chip.assign_integer(ctx, a_assigned.into(), Range::Remainder)?;
+                    let tower = integer_chip.square_tower(ctx, &a_assigned, k)?;
+                    assert_eq!(tower.len(), k + 1);
+
+                    for (i, power) in tower.iter().enumerate() {
+                        // a^(2^i)
+                        let expected = a.pow([1u64 << i]);
+                        let expected = t.new_from_big(fe_to_big(expected));
+                        let expected =
+                            integer_chip.assign_integer(ctx, expected.into(), Range::Remainder)?;
+                        integer_chip.assert_equal(ctx, power, &expected)?;
+                    }
+
+                    let tower = integer_chip.square_tower(ctx, &a_assigned, 0)?;
+                    assert_eq!(tower.len(), 1);
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitRecurrence,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // s_i = a * s_(i-1) + b * s_(i-2)
+                    let (a, b) = (W::random(OsRng), W::from(3));
+                    let mut sequence = vec![W::ZERO, W::ONE];
+                    for i in 2..8 {
+                        sequence.push(a * sequence[i - 1] + b * sequence[i - 2]);
+                    }
+                    let sequence = sequence
+                        .into_iter()
+                        .map(|s| {
+                            let s = t.new_from_big(fe_to_big(s));
+                            integer_chip.assign_integer(ctx, s.into(), Range::Remainder)
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+
+                    let (a, b) = (t.new_from_big(fe_to_big(a)), t.new_from_big(fe_to_big(b)));
+                    for window in sequence.windows(3) {
+                        integer_chip
+                            .assert_recurrence(ctx, &window[0], &window[1], &window[2], &a, &b)?;
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitRecurrenceViolated,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    // Fibonacci coefficients with 1, 1, 3
+                    let one = t.new_from_big(big_uint::one());
+                    let three = t.new_from_big(big_uint::from(3usize));
+                    let prev2 =
+                        integer_chip.assign_integer(ctx, one.clone().into(), Range::Remainder)?;
+                    let prev1 =
+                        integer_chip.assign_integer(ctx, one.clone().into(), Range::Remainder)?;
+                    let cur = integer_chip.assign_integer(ctx, three.into(), Range::Remainder)?;
+                    integer_chip.assert_recurrence(ctx, &prev2, &prev1, &cur, &one, &one)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    #[derive(Debug)]
+    struct FloorDivisionBackend;
+
+    impl ReduceBackend for FloorDivisionBackend {
+        fn div_rem(&self, value: &big_uint, modulus: &big_uint) -> (big_uint, big_uint) {
+            let quotient = value / modulus;
+            let remainder = value - &quotient * modulus;
+            (quotient, remainder)
+        }
+    }
+
+    /// Backend that counts its divisions
+    #[derive(Debug, Default)]
+    struct CountingBackend(Rc<RefCell<usize>>);
+
+    impl ReduceBackend for CountingBackend {
+        fn div_rem(&self, value: &big_uint, modulus: &big_uint) -> (big_uint, big_uint) {
+            *self.0.borrow_mut() += 1;
+            value.div_rem(modulus)
+        }
+    }
+
+    fn assert_same_witness<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>(
+        w_0: MaybeReduced<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        w_1: MaybeReduced<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        long: bool,
+    ) {
+        w_0.result()
+            .zip(w_1.result())
+            .map(|(r_0, r_1)| assert_eq!(r_0.value(), r_1.value()));
+        if long {
+            w_0.long()
+                .zip(w_1.long())
+                .map(|(q_0, q_1)| assert_eq!(q_0.value(), q_1.value()));
+        } else {
+            w_0.short()
+                .zip(w_1.short())
+                .map(|(q_0, q_1)| assert_eq!(q_0, q_1));
+        }
+        for (v_0, v_1) in w_0
+            .residues()
+            .into_iter()
+            .chain(w_0.intermediates())
+            .zip(w_1.residues().into_iter().chain(w_1.intermediates()))
+        {
+            v_0.zip(v_1).map(|(v_0, v_1)| assert_eq!(v_0, v_1));
+        }
+    }
+
+    fn run_reduce_backend<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>() {
+        let default = Rc::new(rns::<W, N, BIT_LEN_LIMB>());
+        let custom = Rc::new(rns::<W, N, BIT_LEN_LIMB>().with_reduce_backend(FloorDivisionBackend));
+        let t_default = TestRNS {
+            rns: Rc::clone(&default),
+        };
+        let t_custom = TestRNS {
+            rns: Rc::clone(&custom),
+        };
+
+        for _ in 0..10 {
+            let (a, b) = (
+                t_default.rand_in_operand_range().value(),
+                t_default.rand_in_operand_range().value(),
+            );
+            let (a_0, b_0) = (
+                t_default.new_from_big(a.clone()),
+                t_default.new_from_big(b.clone()),
+            );
+            let (a_1, b_1) = (t_custom.new_from_big(a), t_custom.new_from_big(b));
+
+            let w_0 = Value::known(a_0.mul(&b_0)).into();
+            let w_1 = Value::known(a_1.mul(&b_1)).into();
+            assert_same_witness(w_0, w_1, true);
+
+            let w_0 = Value::known(a_0.div(&b_0)).into();
+            let w_1 = Value::known(a_1.div(&b_1)).into();
+            assert_same_witness(w_0, w_1, true);
+
+            let a: [N; NUMBER_OF_LIMBS] = t_default
+                .rand_in_unreduced_range()
+                .limbs()
+                .try_into()
+                .unwrap();
+            let (a_0, a_1) = (
+                Integer::from_limbs(&a, Rc::clone(&default)),
+                Integer::from_limbs(&a, Rc::clone(&custom)),
+            );
+            let w_0 = Value::known(a_0.reduce()).into();
+            let w_1 = Value::known(a_1.reduce()).into();
+            assert_same_witness(w_0, w_1, false);
+        }
+    }
+
+    impl_circuit!(
+        TestCircuitDet2,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let (x, y, k) = (W::random(OsRng), W::random(OsRng), W::random(OsRng));
+                    let matrices = [
+                        [
+                            W::random(OsRng),
+                            W::random(OsRng),
+                            W::random(OsRng),
+                            W::random(OsRng),
+                        ],
+                        [W::from(3), W::from(5), W::from(7), W::from(2)],
+                        // Singular, second column is multiple of the first one
+                        [x, k * x, y, k * y],
+                    ];
+                    for (i, [a, b, c, d]) in matrices.into_iter().enumerate() {
+                        let expected = a * d - b * c;
+                        if i == matrices.len() - 1 {
+                            assert_eq!(expected, W::ZERO);
+                        }
+
+                        let [a, b, c, d] = [a, b, c, d].map(|e| t.new_from_big(fe_to_big(e)));
+                        let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                        let b = &integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+                        let c = &integer_chip.assign_integer(ctx, c.into(), Range::Remainder)?;
+                        let d = &integer_chip.assign_integer(ctx, d.into(), Range::Remainder)?;
+                        let det = integer_chip.det2(ctx, a, b, c, d)?;
+
+                        let expected = t.new_from_big(fe_to_big(expected));
+                        let expected =
+                            integer_chip.assign_integer(ctx, expected.into(), Range::Remainder)?;
+                        integer_chip.assert_equal(ctx, &det, &expected)?;
+                    }
+
+                    // Fused reduction takes fewer rows than reducing both products and
+                    // their difference
+                    let [a, b, c, d] = [(); 4].map(|_| t.rand_in_remainder_range());
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = &integer_chip.assign_integer(ctx, b.into(), Range::Remainder)?;
+                    let c = &integer_chip.assign_integer(ctx, c.into(), Range::Remainder)?;
+                    let d = &integer_chip.assign_integer(ctx, d.into(), Range::Remainder)?;
+
+                    let offset = ctx.offset();
+                    let det_0 = integer_chip.det2(ctx, a, b, c, d)?;
+                    let fused = ctx.offset() - offset;
+
+                    let offset = ctx.offset();
+                    let ad = &integer_chip.mul(ctx, a, d)?;
+                    let bc = &integer_chip.mul(ctx, b, c)?;
+                    let det_1 = &integer_chip.sub(ctx, ad, bc)?;
+                    let det_1 = integer_chip.reduce(ctx, det_1)?;
+                    let naive = ctx.offset() - offset;
+
+                    integer_chip.assert_equal(ctx, &det_0, &det_1)?;
+                    assert!(fused < naive);
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitSpans,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let spans = Rc::new(RefCell::new(vec![]));
+            let integer_chip = {
+                let spans = Rc::clone(&spans);
+                self.integer_chip(config.clone())
+                    .with_span_callback(move |op, start, end| {
+                        spans.borrow_mut().push((op.to_string(), start, end))
+                    })
+            };
+            let t = self.tester();
+            let offset = 0;
+            let end = layouter.assign_region(
+                || "region 0",
+                |region| {
+                    spans.borrow_mut().clear();
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let a = t.rand_in_remainder_range();
+                    let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                    let b = t.rand_in_unreduced_range();
+                    let b = &integer_chip.assign_integer(ctx, b.into(), Range::Unreduced)?;
+                    let c = &integer_chip.add(ctx, a, b)?;
+                    let c = &integer_chip.mul(ctx, c, a)?;
+                    let d = &integer_chip.sub(ctx, c, b)?;
+                    integer_chip.assert_not_equal(ctx, d, a)?;
+
+                    Ok(ctx.offset())
+                },
+            )?;
+
+            let spans = spans.borrow();
+            let ops = spans
+                .iter()
+                .map(|(op, _, _)| op.as_str())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                ops,
+                vec![
+                    "assign_integer",
+                    "assign_integer",
+                    "add",
+                    "mul",
+                    "sub",
+                    "assert_not_equal"
+                ]
+            );
+            // Spans tile the region without gaps
+            assert_eq!(spans.first().unwrap().1, offset);
+            for (span, next) in spans.iter().zip(spans.iter().skip(1)) {
+                assert!(span.1 < span.2);
+                assert_eq!(span.2, next.1);
+            }
+            assert_eq!(spans.last().unwrap().2, end);
+
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitCbrt,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let x = W::random(OsRng);
+                    let mut values = vec![x.square() * x, W::ZERO];
+                    if cubic_non_residue::<W>().is_some() {
+                        let non_residue = std::iter::repeat_with(|| W::random(OsRng))
+                            .find(|a| cube_root(*a).is_none())
+                            .unwrap();
+                        values.push(non_residue);
+                    }
+
+                    for a in values {
+                        let expected = cube_root(a);
+                        if let Some(r) = expected {
+                            assert_eq!(r.square() * r, a);
+                        }
+
+                        let a = t.new_from_big(fe_to_big(a));
+                        let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+                        let (r, has_root) = integer_chip.cbrt(ctx, a)?;
+                        main_gate.assert_equal_to_constant(
+                            ctx,
+                            &has_root,
+                            N::from(expected.is_some() as u64),
+                        )?;
+                        if expected.is_some() {
+                            let r_square = &integer_chip.square(ctx, &r)?;
+                            let r_cube = &integer_chip.mul(ctx, r_square, &r)?;
+                            integer_chip.assert_equal(ctx, r_cube, a)?;
+                        }
+                    }
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitSmallInteger,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let main_gate = MainGate::<N>::new(config.main_gate_config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+
+                    let bit_len = BIT_LEN_LIMB / 4;
+                    let a =
+                        &integer_chip.assign_small(ctx, Value::known(N::from(1000)), bit_len)?;
+                    let b = &integer_chip.assign_small(ctx, Value::known(N::from(77)), bit_len)?;
+                    let flag = &integer_chip.assign_small(ctx, Value::known(N::ONE), 1)?;
+                    assert_eq!(*flag.max_val(), big_uint::one());
+
+                    // Arithmetic takes a single main gate row
+                    let offset = ctx.offset();
+                    let sum = &integer_chip.add_small(ctx, a, b)?;
+                    let c = &integer_chip.mul_small(ctx, sum, flag)?;
+                    let c = &integer_chip.mul_small(ctx, c, a)?;
+                    assert_eq!(ctx.offset() - offset, 3);
+                    let max_val = (big_uint::one() << bit_len) - 1usize;
+                    assert_eq!(*sum.max_val(), &max_val * 2usize);
+                    assert_eq!(*c.max_val(), &max_val * &max_val * 2usize);
+                    main_gate.assert_equal_to_constant(ctx, c.value(), N::from(1077 * 1000))?;
+
+                    // Bound would exceed a limb
+                    let full =
+                        &integer_chip.assign_small(ctx, Value::known(N::ONE), BIT_LEN_LIMB)?;
+                    assert!(integer_chip.add_small(ctx, full, full).is_err());
+                    assert!(integer_chip.mul_small(ctx, full, a).is_err());
+                    assert!(integer_chip
+                        .assign_small(ctx, Value::known(N::ONE), bit_len + 1)
+                        .is_err());
+
+                    // Promoted integer works with integer instructions
+                    let c = &integer_chip.promote(ctx, c)?;
+                    let expected = t.new_from_big(big_uint::from(1077usize * 1000));
+                    let expected =
+                        &integer_chip.assign_integer(ctx, expected.into(), Range::Remainder)?;
+                    integer_chip.assert_equal(ctx, c, expected)?;
+                    integer_chip.assert_strict_bit(ctx, &integer_chip.promote(ctx, flag)?)?;
+
+                    let x = t.rand_in_field();
+                    let product = (x.value() * 1077usize * 1000usize) % &self.rns.wrong_modulus;
+                    let product = t.new_from_big(product);
+                    let x = &integer_chip.assign_integer(ctx, x.into(), Range::Remainder)?;
+                    let x_mul_c = &integer_chip.mul(ctx, x, c)?;
+                    let product =
+                        &integer_chip.assign_integer(ctx, product.into(), Range::Remainder)?;
+                    integer_chip.assert_equal(ctx, x_mul_c, product)?;
+
+                    Ok(())
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    /// Toy hasher that folds inputs with `s = (s + x)^3` starting from `s = 7`
+    #[derive(Clone, Debug)]
+    struct TestHasher<N: PrimeField>(MainGate<N>);
+
+    impl<N: PrimeField> TestHasher<N> {
+        fn native(inputs: &[N]) -> N {
+            inputs.iter().fold(N::from(7), |state, input| {
+                let sum = state + input;
+                sum.square() * sum
+            })
+        }
+    }
+
+    /// Stand-in for a binding commitment that hashes values followed by the
+    /// blinding factor
+    impl<N: PrimeField> CommitInstructions<N> for TestHasher<N> {
+        fn commit(
+            &self,
+            ctx: &mut RegionCtx<'_, N>,
+            values: &[AssignedValue<N>],
+            blinding: &AssignedValue<N>,
+        ) -> Result<Vec<AssignedValue<N>>, Error> {
+            let inputs = values
+                .iter()
+                .chain(std::iter::once(blinding))
+                .cloned()
+                .collect::<Vec<_>>();
+            Ok(vec![self.hash(ctx, &inputs)?])
+        }
+    }
+
+    impl<N: PrimeField> HashInstructions<N> for TestHasher<N> {
+        fn hash(
+            &self,
+            ctx: &mut RegionCtx<'_, N>,
+            inputs: &[AssignedValue<N>],
+        ) -> Result<AssignedValue<N>, Error> {
+            let mut state = self.0.assign_constant(ctx, N::from(7))?;
+            for input in inputs {
+                let sum = self.0.add(ctx, &state, input)?;
+                let square = self.0.mul(ctx, &sum, &sum)?;
+                state = self.0.mul(ctx, &square, &sum)?;
+            }
+            Ok(state)
+        }
+    }
+
+    fn assign_merkle_member<W: PrimeField, N: PrimeField, const BIT_LEN_LIMB: usize>(
+        integer_chip: &IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        t: &TestRNS<W, N, BIT_LEN_LIMB>,
+        ctx: &mut RegionCtx<'_, N>,
+        tamper: bool,
+    ) -> Result<(), Error> {
+        let main_gate = integer_chip.main_gate();
+
+        let a = t.rand_in_remainder_range();
+        let a = &integer_chip.assign_integer(ctx, a.into(), Range::Remainder)?;
+        let packed: Value<Vec<N>> = integer_chip
+            .pack(ctx, a)?
+            .iter()
+            .map(|value| value.value().cloned())
+            .collect();
+
+        let siblings = (0..3)
+            .map(|i| (N::random(OsRng), i % 2 == 0))
+            .collect::<Vec<_>>();
+        let root = packed.map(|packed| {
+            siblings
+                .iter()
+                .fold(TestHasher::native(&packed), |node, (sibling, is_left)| {
+                    if *is_left {
+                        TestHasher::native(&[*sibling, node])
+                    } else {
+                        TestHasher::native(&[node, *sibling])
+                    }
+                })
+        });
+        let root = main_gate.assign_value(ctx, root)?;
+
+        let path = siblings
+            .iter()
+            .enumerate()
+            .map(|(i, (sibling, is_left))| {
+                let sibling = if tamper && i == 1 {
+                    *sibling + N::ONE
+                } else {
+                    *sibling
+                };
+                let sibling = main_gate.assign_value(ctx, Value::known(sibling))?;
+                let is_left = main_gate.assign_bit(ctx, Value::known(N::from(*is_left as u64)))?;
+                Ok((sibling, is_left))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let hasher = TestHasher(main_gate.clone());
+        integer_chip.assert_merkle_member(ctx, a, &path, &root, &hasher)
+    }
+
+    impl_circuit!(
+        TestCircuitMerkleMember,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    assign_merkle_member(&integer_chip, &t, ctx, false)
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    impl_circuit!(
+        TestCircuitMerkleMemberTampered,
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<N>,
+        ) -> Result<(), Error> {
+            let integer_chip = self.integer_chip(config.clone());
+            let t = self.tester();
+            layouter.assign_region(
+                || "region 0",
+                |region| {
+                    let offset = 0;
+                    let ctx = &mut RegionCtx::new(region, offset);
+                    assign_merkle_member(&integer_chip, &t, ctx, true)
+                },
+            )?;
+            config.config_range(&mut layouter)
+        }
+    );
+
+    macro_rules! test_circuit_runner {
+        (
+            $verify:ident, $circuit:ident, $([$wrong_field:ident, $native_field:ident, $bit_len_limb:expr]),*
+        ) => {
+            $(
+                let (rns, _):(Rns<$wrong_field, $native_field, NUMBER_OF_LIMBS, $bit_len_limb>, u32) = setup();
+
+                let circuit = $circuit::<$wrong_field, $native_field, $bit_len_limb> { rns: Rc::new(rns) };
+            let instance = vec![vec![]];
+            $verify(&circuit, instance);
+            )*
+        };
+    }
+
+    macro_rules! test_circuit {
+        (
+            $circuit:ident
+        ) => {
+            test_circuit!(mock_prover_verify, $circuit);
+        };
+        (
+            $verify:ident, $circuit:ident
+        ) => {
+            use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+            use crate::curves::pasta::{Fp as PastaFp, Fq as PastaFq};
+            use crate::curves::secp256k1::{Fp as Secp256k1Base, Fq as Secp256k1Scalar};
+            test_circuit_runner!(
+                $verify,
+                $circuit,
+                [PastaFp, PastaFq, 68],
+                [PastaFq, PastaFp, 68],
+                [BnBase, BnScalar, 68],
+                [BnScalar, BnScalar, 68],
+                [Secp256k1Base, BnScalar, 68],
+                [Secp256k1Base, PastaFp, 68],
+                [Secp256k1Base, PastaFq, 68],
+                [Secp256k1Scalar, BnScalar, 68],
+                [Secp256k1Scalar, PastaFp, 68],
+                [Secp256k1Scalar, PastaFq, 68]
             );
         };
     }
@@ -1638,4 +3610,175 @@ mod tests {
     fn test_integer_circuit_sign() {
         test_circuit!(TestCircuitSign);
     }
+    #[test]
+    fn test_integer_circuit_rlc() {
+        test_circuit!(TestCircuitRlc);
+    }
+    #[test]
+    fn test_integer_circuit_reduce_mod_assigned() {
+        test_circuit!(TestCircuitReduceModAssigned);
+    }
+    #[test]
+    fn test_integer_circuit_reduce_mod_assigned_zero_modulus() {
+        test_circuit!(
+            mock_prover_verify_fails,
+            TestCircuitReduceModAssignedZeroModulus
+        );
+    }
+    #[test]
+    fn test_integer_circuit_decompress() {
+        test_circuit!(TestCircuitDecompress);
+    }
+    #[test]
+    fn test_integer_circuit_decompress_non_residue() {
+        test_circuit!(mock_prover_verify_fails, TestCircuitDecompressNonResidue);
+    }
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "limb max value overflows native field")]
+    fn test_integer_circuit_limb_overflow() {
+        test_circuit!(TestCircuitLimbOverflow);
+    }
+    #[test]
+    fn test_integer_circuit_div_by_small_constant() {
+        test_circuit!(TestCircuitDivBySmallConstant);
+    }
+    #[test]
+    #[cfg(feature = "checksum")]
+    fn test_integer_circuit_checksum() {
+        test_circuit!(TestCircuitChecksum);
+    }
+    #[test]
+    #[cfg(feature = "checksum")]
+    #[should_panic(expected = "checksum mismatch in mul")]
+    fn test_integer_circuit_checksum_broken_witness() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+
+        let rns = rns::<BnBase, BnScalar, 68>().with_reduce_backend(OffByOneBackend);
+        let circuit = TestCircuitChecksumBrokenWitness { rns: Rc::new(rns) };
+        mock_prover_verify(&circuit, vec![vec![]]);
+    }
+    #[test]
+    fn test_integer_circuit_gcd() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+        use crate::curves::secp256k1::Fp as Secp256k1Base;
+
+        // Gcd unrolls hundreds of steps, so a single pair is checked on a
+        // few configurations only
+        test_circuit_runner!(
+            mock_prover_verify,
+            TestCircuitGcd,
+            [BnBase, BnScalar, 68],
+            [Secp256k1Base, BnScalar, 68]
+        );
+    }
+    #[test]
+    fn test_integer_circuit_relations() {
+        test_circuit!(TestCircuitRelations);
+    }
+    #[test]
+    fn test_integer_circuit_quadratic_root() {
+        test_circuit!(TestCircuitQuadraticRoot);
+    }
+    #[test]
+    fn test_integer_circuit_quadratic_non_root() {
+        test_circuit!(mock_prover_verify_fails, TestCircuitQuadraticNonRoot);
+    }
+    #[test]
+    fn test_integer_circuit_commitment() {
+        use crate::curves::bn256::Fr as BnScalar;
+        use crate::curves::secp256k1::Fp as Secp256k1Base;
+        use halo2::arithmetic::Field;
+
+        let rns = Rc::new(rns::<Secp256k1Base, BnScalar, 68>());
+        let value = OsRng.gen_biguint(rns.wrong_modulus.bits()) % &rns.wrong_modulus;
+        let blinding = BnScalar::random(OsRng);
+        let circuit = TestCircuitCommitment {
+            rns: Rc::clone(&rns),
+            value: value.clone(),
+            blinding,
+        };
+        let instance = vec![vec![circuit.commitment()]];
+        mock_prover_verify(&circuit, instance.clone());
+
+        // Opening with a wrong blinding factor
+        let circuit = TestCircuitCommitment {
+            rns,
+            value,
+            blinding: blinding + BnScalar::ONE,
+        };
+        mock_prover_verify_fails(&circuit, instance);
+    }
+    #[test]
+    fn test_integer_circuit_limb_sum() {
+        test_circuit!(TestCircuitLimbSum);
+    }
+    #[test]
+    fn test_integer_circuit_reduction_policy() {
+        test_circuit!(TestCircuitReductionPolicy);
+    }
+    #[test]
+    fn test_integer_circuit_reconstruct_secret() {
+        test_circuit!(TestCircuitReconstructSecret);
+    }
+    #[test]
+    fn test_integer_circuit_square_tower() {
+        test_circuit!(TestCircuitSquareTower);
+    }
+    #[test]
+    fn test_integer_circuit_recurrence() {
+        test_circuit!(TestCircuitRecurrence);
+    }
+    #[test]
+    fn test_integer_circuit_recurrence_violated() {
+        test_circuit!(mock_prover_verify_fails, TestCircuitRecurrenceViolated);
+    }
+    #[test]
+    fn test_reduce_backend() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+        use crate::curves::secp256k1::Fp as Secp256k1Base;
+
+        run_reduce_backend::<BnBase, BnScalar, 68>();
+        run_reduce_backend::<Secp256k1Base, BnScalar, 68>();
+    }
+    #[test]
+    fn test_div_witness_uses_reduce_backend() {
+        use crate::curves::bn256::{Fq as BnBase, Fr as BnScalar};
+
+        let backend = CountingBackend::default();
+        let divisions = Rc::clone(&backend.0);
+        let t = TestRNS {
+            rns: Rc::new(rns::<BnBase, BnScalar, 68>().with_reduce_backend(backend)),
+        };
+        let (a, b) = (t.rand_in_field(), t.rand_in_field());
+        *divisions.borrow_mut() = 0;
+        let _ = a.div(&b);
+        // Result, quotient of the product and its correction are all reduced
+        // by the backend
+        assert_eq!(*divisions.borrow(), 3);
+    }
+    #[test]
+    fn test_integer_circuit_det2() {
+        test_circuit!(TestCircuitDet2);
+    }
+    #[test]
+    fn test_integer_circuit_spans() {
+        test_circuit!(TestCircuitSpans);
+    }
+    #[test]
+    fn test_integer_circuit_cbrt() {
+        test_circuit!(TestCircuitCbrt);
+    }
+    #[test]
+    fn test_integer_circuit_small_integer() {
+        test_circuit!(TestCircuitSmallInteger);
+    }
+    #[test]
+    fn test_integer_circuit_merkle_member() {
+        test_circuit!(TestCircuitMerkleMember);
+    }
+    #[test]
+    fn test_integer_circuit_merkle_member_tampered() {
+        test_circuit!(mock_prover_verify_fails, TestCircuitMerkleMemberTampered);
+    }
 }
@@ -1,14 +1,67 @@
 use crate::chip::IntegerChip;
+use crate::relations::RelationRecorder;
 use crate::rns::Integer;
 use crate::{AssignedInteger, AssignedLimb, Common, PrimeField};
 use halo2::plonk::Error;
-use maingate::{fe_to_big, halo2, MainGateInstructions, RegionCtx, Term};
+use maingate::{fe_to_big, halo2, AssignedValue, MainGateInstructions, RegionCtx, Term};
 use num_bigint::BigUint as big_uint;
+use std::fmt::Write;
 use std::rc::Rc;
 
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
 {
+    /// Records limb and native relations of `c = sum(k * x) + constant` where
+    /// each term is given as `(x, k)`
+    fn record_linear(
+        &self,
+        op: &str,
+        c: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        terms: &[(&AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, i64)],
+        constant: Option<&Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    ) {
+        let expression = |r: &RelationRecorder,
+                          c: &AssignedValue<N>,
+                          xs: Vec<&AssignedValue<N>>,
+                          constant: Option<N>| {
+            let mut expression = r.variable(c);
+            for (x, (_, k)) in xs.into_iter().zip(terms.iter()) {
+                let x = r.variable(x);
+                match k {
+                    1 => write!(expression, " - {x}"),
+                    -1 => write!(expression, " + {x}"),
+                    k if *k > 0 => write!(expression, " - {k} * {x}"),
+                    k => write!(expression, " + {} * {x}", -k),
+                }
+                .unwrap();
+            }
+            if let Some(constant) = constant {
+                write!(expression, " - {}", fe_to_big(constant)).unwrap();
+            }
+            expression
+        };
+        for (i, c_i) in c.limbs().iter().enumerate() {
+            self.record_relation(op, "limb", |r| {
+                let xs = terms.iter().map(|(x, _)| x.limb(i)).collect();
+                expression(
+                    r,
+                    c_i.as_ref(),
+                    xs,
+                    constant.map(|constant| constant.limbs()[i]),
+                )
+            });
+        }
+        self.record_relation(op, "native", |r| {
+            let xs = terms.iter().map(|(x, _)| x.native()).collect();
+            expression(
+                r,
+                c.native(),
+                xs,
+                constant.map(|constant| constant.native()),
+            )
+        });
+    }
+
     pub(super) fn add_generic(
         &self,
         ctx: &mut RegionCtx<'_, N>,
@@ -30,7 +83,14 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .try_into()
             .unwrap();
         let c_native = main_gate.add(ctx, a.native(), b.native())?;
-        Ok(self.new_assigned_integer(&c_limbs, c_native))
+
+        let c = self.new_assigned_integer(&c_limbs, c_native);
+        self.record_linear("add", &c, &[(a, 1), (b, 1)], None);
+        #[cfg(feature = "checksum")]
+        let c = self.derive_checksum("add", c, &[a, b], |checksums| {
+            checksums.map(|c| &c[0] + &c[1])
+        });
+        Ok(c)
     }
 
     pub(super) fn add_add_generic(
@@ -72,7 +132,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             ],
             N::ZERO,
         )?;
-        Ok(self.new_assigned_integer(&c_limbs, c_native))
+
+        let c = self.new_assigned_integer(&c_limbs, c_native);
+        self.record_linear("add_add", &c, &[(a, 1), (b_0, 1), (b_1, 1)], None);
+        Ok(c)
     }
 
     pub(super) fn sub_generic(
@@ -99,7 +162,15 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .try_into()
             .unwrap();
         let c_native = main_gate.sub_with_constant(ctx, a.native(), b.native(), aux.native())?;
-        Ok(self.new_assigned_integer(&c_limbs, c_native))
+
+        let c = self.new_assigned_integer(&c_limbs, c_native);
+        self.record_linear("sub", &c, &[(a, 1), (b, -1)], Some(&aux));
+        // `c = a - b + aux` over integers
+        #[cfg(feature = "checksum")]
+        let c = self.derive_checksum("sub", c, &[a, b], |checksums| {
+            checksums.map(|c| &c[0] + aux.value() + crate::CHECKSUM_MODULUS - &c[1])
+        });
+        Ok(c)
     }
 
     pub(super) fn sub_sub_generic(
@@ -148,7 +219,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             b_1.native(),
             aux.native(),
         )?;
-        Ok(self.new_assigned_integer(&c_limbs, c_native))
+
+        let c = self.new_assigned_integer(&c_limbs, c_native);
+        self.record_linear("sub_sub", &c, &[(a, 1), (b_0, -1), (b_1, -1)], Some(&aux));
+        Ok(c)
     }
 
     pub(super) fn neg_generic(
@@ -171,7 +245,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .try_into()
             .unwrap();
         let c_native = main_gate.neg_with_constant(ctx, a.native(), aux.native())?;
-        Ok(self.new_assigned_integer(&c_limbs, c_native))
+
+        let c = self.new_assigned_integer(&c_limbs, c_native);
+        self.record_linear("neg", &c, &[(a, -1)], Some(&aux));
+        Ok(c)
     }
 
     pub(crate) fn mul2_generic(
@@ -193,7 +270,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .try_into()
             .unwrap();
         let c_native = main_gate.mul2(ctx, a.native())?;
-        Ok(self.new_assigned_integer(&c_limbs, c_native))
+
+        let c = self.new_assigned_integer(&c_limbs, c_native);
+        self.record_linear("mul2", &c, &[(a, 2)], None);
+        Ok(c)
     }
 
     pub(crate) fn mul3_generic(
@@ -215,7 +295,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .try_into()
             .unwrap();
         let c_native = main_gate.mul3(ctx, a.native())?;
-        Ok(self.new_assigned_integer(&c_limbs, c_native))
+
+        let c = self.new_assigned_integer(&c_limbs, c_native);
+        self.record_linear("mul3", &c, &[(a, 3)], None);
+        Ok(c)
     }
 
     pub(crate) fn add_constant_generic(
@@ -239,6 +322,9 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .try_into()
             .unwrap();
         let c_native = main_gate.add_constant(ctx, a.native(), b.native())?;
-        Ok(self.new_assigned_integer(&c_limbs, c_native))
+
+        let c = self.new_assigned_integer(&c_limbs, c_native);
+        self.record_linear("add_constant", &c, &[(a, 1)], Some(b));
+        Ok(c)
     }
 }
@@ -3,7 +3,9 @@ use crate::rns::MaybeReduced;
 use crate::{AssignedInteger, PrimeField};
 use halo2::plonk::Error;
 
-use maingate::{halo2, AssignedValue, MainGateInstructions, RangeInstructions, RegionCtx, Term};
+use maingate::{
+    fe_to_big, halo2, AssignedValue, MainGateInstructions, RangeInstructions, RegionCtx, Term,
+};
 
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
@@ -32,6 +34,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                 Ok(residue)
             })
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+        self.record_range("assert_equal", &quotient, BIT_LEN_LIMB);
+        for v in residues.iter() {
+            self.record_range("assert_equal", v, self.rns.red_v_bit_len);
+        }
 
         // Assign intermediate values
         let t: Vec<AssignedValue<N>> = a
@@ -50,11 +56,14 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                 Ok(t)
             })
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+        self.record_reduction_intermediates("assert_equal", &t, a, &quotient);
 
         // Constrain residues
         let lsh_one = self.rns.left_shifter(1);
         let lsh_two = self.rns.left_shifter(2);
         let mut carry = Term::Zero;
+        let mut carry_var: Option<&AssignedValue<N>> = None;
+        let (lsh_one_big, lsh_two_big) = (fe_to_big(lsh_one), fe_to_big(lsh_two));
         for (t_chunk, v) in t.chunks(2).zip(residues.iter()) {
             if t_chunk.len() == 2 {
                 let (t_lo, t_hi) = (&t_chunk[0], &t_chunk[1]);
@@ -69,7 +78,19 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                     ],
                     zero,
                 )?;
+                self.record_relation("assert_equal", "residue", |rec| {
+                    let carry = carry_var
+                        .map(|carry| format!(" + {}", rec.variable(carry)))
+                        .unwrap_or_default();
+                    format!(
+                        "{} + {lsh_one_big} * {} - {lsh_two_big} * {}{carry}",
+                        rec.variable(t_lo),
+                        rec.variable(t_hi),
+                        rec.variable(v),
+                    )
+                });
                 carry = Term::Assigned(v, one);
+                carry_var = Some(v);
             } else {
                 let t = &t[0];
                 main_gate.assert_zero_sum(
@@ -82,6 +103,16 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                     ],
                     zero,
                 )?;
+                self.record_relation("assert_equal", "residue", |rec| {
+                    let carry = carry_var
+                        .map(|carry| format!(" + {}", rec.variable(carry)))
+                        .unwrap_or_default();
+                    format!(
+                        "{} - {lsh_one_big} * {}{carry}",
+                        rec.variable(t),
+                        rec.variable(v),
+                    )
+                });
             }
         }
 
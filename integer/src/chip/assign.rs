@@ -30,6 +30,12 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         let max_val_msb = (big_uint::one() << bit_len_limb_msb) - 1usize;
         let max_val = (big_uint::one() << BIT_LEN_LIMB) - 1usize;
 
+        #[cfg(feature = "checksum")]
+        let checksum = integer
+            .0
+            .as_ref()
+            .map(|integer| integer.value() % crate::CHECKSUM_MODULUS);
+
         let limbs = integer
             .0
             .map(|integer| integer.limbs())
@@ -79,6 +85,16 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                     .collect::<Result<Vec<AssignedLimb<N>>, Error>>()
             }
         }?;
+        if !matches!(range, Range::Unreduced) {
+            for (i, limb) in limbs.iter().enumerate() {
+                let bit_len = if i == NUMBER_OF_LIMBS - 1 {
+                    bit_len_limb_msb
+                } else {
+                    BIT_LEN_LIMB
+                };
+                self.record_range("assign_integer", limb.as_ref(), bit_len);
+            }
+        }
 
         let limbs_to_compose: Vec<Term<N>> = limbs
             .iter()
@@ -86,8 +102,24 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .map(|(limb, sh)| Term::Assigned(limb.as_ref(), *sh))
             .collect();
         let native = main_gate.compose(ctx, &limbs_to_compose, N::ZERO)?;
+        self.record_relation("assign_integer", "native", |r| {
+            let sum = limbs
+                .iter()
+                .zip(self.rns.left_shifters.iter())
+                .map(|(limb, sh)| format!("{} * {}", fe_to_big(*sh), r.variable(limb.as_ref())))
+                .collect::<Vec<_>>()
+                .join(" + ");
+            format!("{} - ({sum})", r.variable(&native))
+        });
+
+        let assigned = self.new_assigned_integer(&limbs.try_into().unwrap(), native);
+        #[cfg(feature = "checksum")]
+        let assigned = AssignedInteger {
+            checksum: (!matches!(range, Range::Unreduced)).then_some(checksum),
+            ..assigned
+        };
 
-        Ok(self.new_assigned_integer(&limbs.try_into().unwrap(), native))
+        Ok(assigned)
     }
 
     pub(super) fn assign_constant_generic(
@@ -117,6 +149,14 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .collect::<Result<Vec<AssignedLimb<N>>, Error>>()?;
 
         let native = main_gate.assign_constant(ctx, integer.native())?;
+        for (limb, constant) in assigned_limbs.iter().zip(limbs.iter()) {
+            self.record_relation("assign_constant", "constant", |r| {
+                format!("{} - {}", r.variable(limb.as_ref()), fe_to_big(*constant))
+            });
+        }
+        self.record_relation("assign_constant", "constant", |r| {
+            format!("{} - {}", r.variable(&native), fe_to_big(integer.native()))
+        });
 
         Ok(self.new_assigned_integer(&assigned_limbs.try_into().unwrap(), native))
     }
@@ -0,0 +1,151 @@
+use super::{IntegerChip, IntegerInstructions, Range};
+use crate::rns::Integer;
+use crate::{AssignedInteger, PrimeField};
+use halo2::plonk::Error;
+use maingate::{big_to_fe, halo2, modulus, AssignedCondition, MainGateInstructions, RegionCtx};
+use num_bigint::BigUint as big_uint;
+use num_traits::{One, Zero};
+use std::rc::Rc;
+
+fn pow<W: PrimeField>(a: W, e: &big_uint) -> W {
+    a.pow_vartime(e.to_u64_digits())
+}
+
+/// Returns the smallest cubic non residue of the field or `None` if every
+/// element has a cube root, that is if `p != 1 mod 3`
+pub(crate) fn cubic_non_residue<W: PrimeField>() -> Option<W> {
+    let p_minus_one = modulus::<W>() - 1usize;
+    if !(&p_minus_one % 3usize).is_zero() {
+        return None;
+    }
+    let e = p_minus_one / 3usize;
+    (2u64..).map(W::from).find(|z| pow(*z, &e) != W::ONE)
+}
+
+/// Returns a cube root of `a` or `None` if `a` is not a cubic residue
+pub(crate) fn cube_root<W: PrimeField>(a: W) -> Option<W> {
+    if a == W::ZERO {
+        return Some(W::ZERO);
+    }
+    let p_minus_one = modulus::<W>() - 1usize;
+    let z = match cubic_non_residue::<W>() {
+        Some(z) => z,
+        // Cubing is a permutation and `(2p - 1) / 3` inverts it
+        None => return Some(pow(a, &((modulus::<W>() * 2usize - 1usize) / 3usize))),
+    };
+    if pow(a, &(&p_minus_one / 3usize)) != W::ONE {
+        return None;
+    }
+
+    // `p - 1 = 3^s * t` where `t` is not divisible by 3
+    let mut s = 0;
+    let mut t = p_minus_one;
+    while (&t % 3usize).is_zero() {
+        t /= 3usize;
+        s += 1;
+    }
+
+    // `u = 3^-1 mod t` so that `x^3 = a * err` where `err` is in the 3-Sylow
+    // subgroup
+    let u = if (&t % 3usize).is_one() {
+        (&t * 2usize + 1usize) / 3usize
+    } else {
+        (&t + 1usize) / 3usize
+    };
+    let x = pow(a, &u);
+    let target = (x.square() * x * a.invert().unwrap()).invert().unwrap();
+
+    // Discrete log of `target` to the base `g` which generates the 3-Sylow
+    // subgroup, digit by digit
+    let g = pow(z, &t);
+    let g_inv = g.invert().unwrap();
+    let omega = pow(g, &big_uint::from(3usize).pow(s - 1));
+    let mut log = big_uint::zero();
+    let mut power = big_uint::one();
+    for i in 0..s {
+        let h = pow(
+            target * pow(g_inv, &log),
+            &big_uint::from(3usize).pow(s - 1 - i),
+        );
+        if h == omega {
+            log += &power;
+        } else if h == omega.square() {
+            log += &power * 2usize;
+        }
+        power *= 3usize;
+    }
+
+    // `target` is a cube so its log is divisible by 3
+    Some(x * pow(g, &(log / 3usize)))
+}
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub(super) fn cbrt_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    > {
+        let main_gate = self.main_gate();
+        let z = cubic_non_residue::<W>();
+
+        // Exactly one of `a`, `a * z` and `a * z^2` is a cubic residue when `a`
+        // is not zero. `e` is the exponent of `z` that makes it a residue.
+        let witness = a.integer().map(|a| {
+            let a: W = big_to_fe(a.value());
+            let (r, e) = match z {
+                None => (cube_root(a).unwrap(), 0),
+                Some(z) => [W::ONE, z, z.square()]
+                    .into_iter()
+                    .zip(0u8..)
+                    .find_map(|(factor, e)| cube_root(a * factor).map(|r| (r, e)))
+                    .unwrap(),
+            };
+            (Integer::from_fe(r, Rc::clone(&self.rns)), e)
+        });
+        let r = witness.as_ref().map(|(r, _)| r.clone());
+        let r = self.assign_integer(ctx, r.into(), Range::Remainder)?;
+        let r_square = &self.square(ctx, &r)?;
+        let r_cube = &self.mul(ctx, r_square, &r)?;
+
+        let z = match z {
+            Some(z) => z,
+            None => {
+                self.assert_equal(ctx, r_cube, a)?;
+                let cond = main_gate.assign_constant(ctx, N::ONE)?;
+                return Ok((r, cond));
+            }
+        };
+
+        let e_is_one = witness.as_ref().map(|(_, e)| N::from((*e == 1) as u64));
+        let e_is_two = witness.as_ref().map(|(_, e)| N::from((*e == 2) as u64));
+        let e_is_one = &main_gate.assign_bit(ctx, e_is_one)?;
+        let e_is_two = &main_gate.assign_bit(ctx, e_is_two)?;
+
+        // r^3 = a * factor where factor is one of `1`, `z` or `z^2`
+        let one = &self.assign_constant(ctx, W::ONE)?;
+        let z_square = &self.assign_constant(ctx, z.square())?;
+        let z = &self.assign_constant(ctx, z)?;
+        let factor = &self.select(ctx, z_square, one, e_is_two)?;
+        let factor = &self.select(ctx, z, factor, e_is_one)?;
+        let a_mul_factor = &self.mul(ctx, a, factor)?;
+        self.assert_equal(ctx, r_cube, a_mul_factor)?;
+
+        let has_root = main_gate.or(ctx, e_is_one, e_is_two)?;
+        let has_root = main_gate.not(ctx, &has_root)?;
+
+        // Zero is a cube of zero under any factor so the root is asserted not to be
+        // zero whenever the flag says there is no cube root
+        let r_or_one = &self.select(ctx, one, &r, &has_root)?;
+        self.assert_not_zero(ctx, r_or_one)?;
+
+        Ok((r, has_root))
+    }
+}
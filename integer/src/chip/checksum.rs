@@ -0,0 +1,86 @@
+use super::{IntegerChip, IntegerInstructions, Range};
+use crate::rns::{Common, Integer};
+use crate::{AssignedInteger, PrimeField, CHECKSUM_MODULUS};
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use maingate::{halo2, RegionCtx};
+use num_bigint::BigUint as big_uint;
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Attaches the checksum that `derive` computes from checksums of
+    /// `operands` to `result`. Witness of `result` is checked against it so
+    /// that a broken witness panics at the operation that produced it. If
+    /// any operand has no checksum `result` is returned as is.
+    pub(super) fn derive_checksum(
+        &self,
+        op: &str,
+        result: AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        operands: &[&AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        derive: impl FnOnce(Value<Vec<big_uint>>) -> Value<big_uint>,
+    ) -> AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        let checksums = match operands
+            .iter()
+            .map(|operand| operand.checksum.clone())
+            .collect::<Option<Vec<Value<big_uint>>>>()
+        {
+            Some(checksums) => checksums,
+            None => return result,
+        };
+        let checksum = derive(checksums.into_iter().collect()).map(|c| c % CHECKSUM_MODULUS);
+        checksum
+            .as_ref()
+            .zip(result.integer())
+            .map(|(checksum, result)| {
+                assert_eq!(
+                    &(result.value() % CHECKSUM_MODULUS),
+                    checksum,
+                    "checksum mismatch in {op}"
+                )
+            });
+        AssignedInteger {
+            checksum: Some(checksum),
+            ..result
+        }
+    }
+
+    /// Attaches checksum of `result` where product of `operands` is equal to
+    /// `quotient * p + result` over integers
+    pub(super) fn derive_reduction_checksum(
+        &self,
+        op: &str,
+        result: AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        operands: &[&AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        quotient: Value<big_uint>,
+    ) -> AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        let p = &self.rns.wrong_modulus;
+        self.derive_checksum(op, result, operands, |checksums| {
+            checksums.zip(quotient).map(|(checksums, quotient)| {
+                checksums.iter().product::<big_uint>() + CHECKSUM_MODULUS
+                    - (quotient * p) % CHECKSUM_MODULUS
+            })
+        })
+    }
+
+    /// Asserts that value of `a` modulo [`CHECKSUM_MODULUS`] is equal to its
+    /// checksum. Checksums are recorded at assignment and carried through
+    /// `add`, `sub`, `mul`, `square` and `reduce`. Integers without a checksum
+    /// are skipped.
+    pub fn assert_checksum(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        let checksum = match &a.checksum {
+            Some(checksum) => checksum.clone(),
+            None => return Ok(()),
+        };
+
+        let modulus = &self.assign_constant(ctx, W::from(CHECKSUM_MODULUS))?;
+        let remainder = &self.reduce_mod_assigned(ctx, a, modulus)?;
+        let checksum = checksum.map(|checksum| Integer::from_big(checksum, self.rns()));
+        let checksum = &self.assign_integer(ctx, checksum.into(), Range::Remainder)?;
+        self.assert_strict_equal(ctx, remainder, checksum)
+    }
+}
@@ -0,0 +1,35 @@
+use super::{IntegerChip, IntegerInstructions, Range};
+use crate::instructions::CommitInstructions;
+use crate::{AssignedInteger, PrimeField, UnassignedInteger};
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use maingate::{halo2, MainGateInstructions, RegionCtx};
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub(super) fn open_commitment_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: UnassignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        blinding: Value<N>,
+        commitment_instance: usize,
+        committer: &dyn CommitInstructions<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let main_gate = self.main_gate();
+
+        let value = self.assign_integer(ctx, value, Range::Remainder)?;
+        // Packing asserts the canonical form so that an opening can't be shifted
+        // by the wrong modulus
+        let packed = self.pack(ctx, &value)?;
+        let blinding = main_gate.assign_value(ctx, blinding)?;
+        let commitment = committer.commit(ctx, &packed, &blinding)?;
+
+        for (i, commitment) in commitment.iter().enumerate() {
+            let public_commitment = main_gate.assign_from_instance(ctx, commitment_instance + i)?;
+            main_gate.assert_equal(ctx, commitment, &public_commitment)?;
+        }
+
+        Ok(value)
+    }
+}
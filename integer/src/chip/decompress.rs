@@ -0,0 +1,45 @@
+use super::{IntegerChip, IntegerInstructions, Range};
+use crate::rns::{Common, Integer};
+use crate::{AssignedInteger, PrimeField};
+use halo2::plonk::Error;
+use maingate::{big_to_fe, halo2, AssignedCondition, MainGateInstructions, RegionCtx};
+use std::rc::Rc;
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub(super) fn decompress_x_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        x: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        sign: &AssignedCondition<N>,
+        b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let y = x.integer().zip(sign.value()).map(|(x, sign)| {
+            let x: W = big_to_fe(x.value());
+            let y_square = x.square() * x + big_to_fe::<W>(b.value());
+            // Any witness will fail `y^2 = x^3 + b` for a non residue
+            let y: W = Option::from(y_square.sqrt()).unwrap_or(W::ZERO);
+            let y = if bool::from(y.is_odd()) == (*sign == N::ONE) {
+                y
+            } else {
+                -y
+            };
+            Integer::from_fe(y, Rc::clone(&self.rns))
+        });
+        let y = self.assign_integer(ctx, y.into(), Range::Remainder)?;
+
+        // y^2 = x^3 + b
+        let y_square = &self.square(ctx, &y)?;
+        let x_square = &self.square(ctx, x)?;
+        let x_cube = &self.mul(ctx, x_square, x)?;
+        let x_cube_plus_b = &self.add_constant(ctx, x_cube, b)?;
+        self.assert_equal(ctx, y_square, x_cube_plus_b)?;
+
+        // `sign` asserts that `y` is in field so that parity is unique
+        let y_sign = self.sign(ctx, &y)?;
+        self.main_gate().assert_equal(ctx, &y_sign, sign)?;
+
+        Ok(y)
+    }
+}
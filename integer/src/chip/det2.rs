@@ -0,0 +1,311 @@
+use super::{IntegerChip, IntegerInstructions, Range};
+use crate::rns::MaybeReduced;
+use crate::{AssignedInteger, PrimeField};
+use halo2::{arithmetic::Field, plonk::Error};
+use maingate::{
+    big_to_fe, fe_to_big, halo2, AssignedValue, CombinationOptionCommon, MainGateInstructions,
+    RangeInstructions, RegionCtx, Term,
+};
+use num_bigint::BigUint as big_uint;
+use num_integer::Integer as _;
+use num_traits::{One, Zero};
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns `aux` that is a multiple of the wrong modulus and not less than
+    /// `b * c` along with its limbs under the binary modulus. Each limb is
+    /// large enough to keep the intermediate value positive after products
+    /// of `b` and `c` limbs are subtracted from it.
+    fn det2_aux(
+        &self,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> (big_uint, [big_uint; NUMBER_OF_LIMBS]) {
+        let wrong_modulus = &self.rns.wrong_modulus;
+        let aux = (b.max_val() * c.max_val()).div_ceil(wrong_modulus) * wrong_modulus;
+
+        // `z_i = x_i + R * borrow_i - borrow_{i-1}` where `x_i` are dense limbs of
+        // `aux`. The last borrow vanishes under the binary modulus.
+        let (b_max, c_max) = (b.max_vals(), c.max_vals());
+        let limb_modulus = &(big_uint::one() << BIT_LEN_LIMB);
+        let mut borrow = big_uint::zero();
+        let limbs = (0..NUMBER_OF_LIMBS)
+            .map(|i| {
+                let x_i = (&aux >> (i * BIT_LEN_LIMB)) % limb_modulus;
+                let subtracted: big_uint = (0..=i).map(|j| &b_max[j] * &c_max[i - j]).sum();
+                let next_borrow = (subtracted + &borrow).div_ceil(limb_modulus);
+                let z_i = x_i + &next_borrow * limb_modulus - &borrow;
+                borrow = next_borrow;
+                z_i
+            })
+            .collect::<Vec<big_uint>>()
+            .try_into()
+            .unwrap();
+
+        (aux, limbs)
+    }
+
+    /// Emulates the binary part of the reduction with maximum values to find
+    /// the bit length of residues. It is rounded up to full sublimbs so that
+    /// no overflow table is needed.
+    fn det2_v_bit_len(
+        &self,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        d: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        aux_limbs: &[big_uint; NUMBER_OF_LIMBS],
+    ) -> usize {
+        let (a_max, d_max) = (a.max_vals(), d.max_vals());
+        let p = self.rns.negative_wrong_modulus_decomposed.map(fe_to_big);
+        let q_max = (0..NUMBER_OF_LIMBS)
+            .map(|i| {
+                if i != NUMBER_OF_LIMBS - 1 {
+                    self.rns.max_reduced_limb.clone()
+                } else {
+                    self.rns.max_most_significant_mul_quotient_limb.clone()
+                }
+            })
+            .collect::<Vec<big_uint>>();
+
+        // Subtracted products are dropped since they only lower the maximum
+        let t = (0..NUMBER_OF_LIMBS)
+            .map(|i| {
+                (0..=i)
+                    .map(|j| &a_max[j] * &d_max[i - j] + &p[j] * &q_max[i - j])
+                    .sum::<big_uint>()
+                    + &aux_limbs[i]
+            })
+            .collect::<Vec<big_uint>>();
+
+        let mut carry = big_uint::zero();
+        let mut bit_len = 1;
+        for chunk in t.chunks(2) {
+            let u = chunk
+                .iter()
+                .rev()
+                .fold(big_uint::zero(), |acc, t| (acc << BIT_LEN_LIMB) + t)
+                + &carry;
+            carry = u >> (chunk.len() * BIT_LEN_LIMB);
+            bit_len = std::cmp::max(carry.bits() as usize, bit_len);
+        }
+
+        let sublimb_bit_len = Self::sublimb_bit_len();
+        bit_len.div_ceil(sublimb_bit_len) * sublimb_bit_len
+    }
+
+    pub(super) fn det2_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        d: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let main_gate = self.main_gate();
+        let (zero, one) = (N::ZERO, N::ONE);
+
+        let negative_wrong_modulus = self.rns.negative_wrong_modulus_decomposed;
+
+        // `a * d - b * c + aux = w * quotient + remainder`
+        let (aux, aux_limbs) = self.det2_aux(b, c);
+        {
+            // Sanity check for completeness
+            let max_reducible_value = &self.rns.max_mul_quotient * &self.rns.wrong_modulus;
+            assert!(a.max_val() * d.max_val() + &aux < max_reducible_value);
+        }
+        let v_bit_len = self.det2_v_bit_len(a, d, &aux_limbs);
+        let aux_limbs: [N; NUMBER_OF_LIMBS] = aux_limbs.map(big_to_fe);
+        let aux_native: N = big_to_fe(aux.clone());
+
+        let witness: MaybeReduced<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> = a
+            .integer()
+            .zip(b.integer())
+            .zip(c.integer())
+            .zip(d.integer())
+            .map(|(((a, b), c), d)| a.det2(&b, &c, &d, &aux, &aux_limbs))
+            .into();
+        let result = witness.result();
+        let quotient = witness.long();
+
+        // Apply ranges
+        let range_chip = self.range_chip();
+        let result = self.assign_integer(ctx, result.into(), Range::Remainder)?;
+        let quotient = &self.assign_integer(ctx, quotient.into(), Range::MulQuotient)?;
+        let residues = witness
+            .residues()
+            .iter()
+            .map(|v| range_chip.assign(ctx, *v, Self::sublimb_bit_len(), v_bit_len))
+            .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+
+        // Witness layout for `NUMBER_OF_LIMBS = 2`:
+        // | A   | B   | C   | D     |
+        // | --- | --- | --- | ----- |
+        // | b_0 | c_0 | -   | t_0   |
+        // | a_0 | d_0 | q_0 | tmp   |
+
+        // | b_0 | c_1 | -   | t_1   |
+        // | a_0 | d_1 | q_1 | tmp_a |
+        // | b_1 | c_0 | -   | tmp_b |
+        // | a_1 | d_0 | q_0 | tmp_c |
+
+        let mut t: Vec<AssignedValue<N>> = vec![];
+
+        // Assign intermediate values
+        for (i, intermediate_value) in witness.intermediates().into_iter().enumerate() {
+            let mut intermediate_value = intermediate_value;
+
+            for j in 0..=i {
+                let k = i - j;
+
+                // Aux limb is added with the first subtracted product
+                let aux = if j == 0 { aux_limbs[i] } else { zero };
+                let t_i = main_gate
+                    .apply(
+                        ctx,
+                        [
+                            Term::Assigned(b.limb(j), zero),
+                            Term::Assigned(c.limb(k), zero),
+                            Term::Zero,
+                            Term::Zero,
+                            Term::Unassigned(intermediate_value, -one),
+                        ],
+                        aux,
+                        CombinationOptionCommon::CombineToNextScaleMul(one, -one).into(),
+                    )?
+                    .swap_remove(4);
+
+                if j == 0 {
+                    // first time we see t_j assignment
+                    t.push(t_i);
+                }
+
+                // update running temp value
+                intermediate_value = intermediate_value
+                    .zip(b.limb(j).value())
+                    .zip(c.limb(k).value())
+                    .map(|((t, b), c)| t + *b * *c - aux);
+
+                let combination_option = if k == 0 {
+                    CombinationOptionCommon::OneLinerMul
+                } else {
+                    CombinationOptionCommon::CombineToNextMul(one)
+                }
+                .into();
+
+                main_gate.apply(
+                    ctx,
+                    [
+                        Term::Assigned(a.limb(j), zero),
+                        Term::Assigned(d.limb(k), zero),
+                        Term::Assigned(quotient.limb(k), negative_wrong_modulus[j]),
+                        Term::Zero,
+                        Term::Unassigned(intermediate_value, -one),
+                    ],
+                    zero,
+                    combination_option,
+                )?;
+
+                // update running temp value
+                intermediate_value = intermediate_value
+                    .zip(a.limb(j).value())
+                    .zip(d.limb(k).value())
+                    .zip(quotient.limb(k).value())
+                    .map(|(((t, a), d), q)| {
+                        let p = negative_wrong_modulus[j];
+                        t - (*a * *d + *q * p)
+                    });
+
+                // Sanity check for the last running subtraction value
+                {
+                    if j == i {
+                        intermediate_value.assert_if_known(Field::is_zero_vartime);
+                    }
+                }
+            }
+        }
+
+        // Record intermediate, native and range relations
+        for (i, t_i) in t.iter().enumerate() {
+            self.record_relation("det2", "intermediate", |r| {
+                let sum = (0..=i)
+                    .map(|j| {
+                        let k = i - j;
+                        let p = fe_to_big(negative_wrong_modulus[j]);
+                        let (a_j, d_k) = (r.variable(a.limb(j)), r.variable(d.limb(k)));
+                        let (b_j, c_k) = (r.variable(b.limb(j)), r.variable(c.limb(k)));
+                        let q_k = r.variable(quotient.limb(k));
+                        format!("{a_j} * {d_k} - {b_j} * {c_k} + {p} * {q_k}")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" + ");
+                let aux = fe_to_big(aux_limbs[i]);
+                format!("{} - ({sum}) - {aux}", r.variable(t_i))
+            });
+        }
+        self.record_relation("det2", "native", |r| {
+            let p = fe_to_big(self.rns.wrong_modulus_in_native_modulus);
+            let (a_n, d_n) = (r.variable(a.native()), r.variable(d.native()));
+            let (b_n, c_n) = (r.variable(b.native()), r.variable(c.native()));
+            let (q_n, r_n) = (r.variable(quotient.native()), r.variable(result.native()));
+            let aux = fe_to_big(aux_native);
+            format!("{a_n} * {d_n} - {b_n} * {c_n} + {aux} - {p} * {q_n} - {r_n}")
+        });
+        for v in residues.iter() {
+            self.record_range("det2", v, v_bit_len);
+        }
+
+        // Constrain binary part of crt
+        self.constrain_binary_crt(
+            ctx,
+            "det2",
+            &t.try_into()
+                .expect("Unexpected failure in AssignedCell -> AssignedValue conversion"),
+            &result,
+            residues,
+        )?;
+
+        // Constrain native part of crt
+        let bc = b
+            .native()
+            .value()
+            .zip(c.native().value())
+            .map(|(b, c)| *b * *c);
+        main_gate.apply(
+            ctx,
+            [
+                Term::Assigned(a.native(), zero),
+                Term::Assigned(d.native(), zero),
+                Term::Assigned(quotient.native(), -self.rns.wrong_modulus_in_native_modulus),
+                Term::Assigned(result.native(), -one),
+                Term::Zero,
+            ],
+            aux_native,
+            CombinationOptionCommon::CombineToNextMul(-one).into(),
+        )?;
+        main_gate.apply(
+            ctx,
+            [
+                Term::Assigned(b.native(), zero),
+                Term::Assigned(c.native(), zero),
+                Term::Zero,
+                Term::Zero,
+                Term::Unassigned(bc, -one),
+            ],
+            zero,
+            CombinationOptionCommon::OneLinerMul.into(),
+        )?;
+
+        #[cfg(feature = "checksum")]
+        let result = {
+            use crate::rns::Common;
+            let (m, p) = (crate::CHECKSUM_MODULUS, &self.rns.wrong_modulus);
+            let quotient = quotient.integer().map(|quotient| quotient.value());
+            self.derive_checksum("det2", result, &[a, b, c, d], |checksums| {
+                checksums.zip(quotient).map(|(c, quotient)| {
+                    &c[0] * &c[3] + &aux + m * m - &c[1] * &c[2] + m - (quotient * p) % m
+                })
+            })
+        };
+        Ok(result)
+    }
+}
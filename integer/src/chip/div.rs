@@ -118,8 +118,21 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             }
         }
 
+        // Record intermediate, native and range relations
+        self.record_intermediates("div_incomplete", &t, &result, b, quotient);
+        self.record_native_product(
+            "div_incomplete",
+            result.native(),
+            b.native(),
+            quotient,
+            a.native(),
+        );
+        for v in residues.iter() {
+            self.record_range("div_incomplete", v, self.rns.mul_v_bit_len);
+        }
+
         // Constrain binary part of crt
-        self.constrain_binary_crt(ctx, &t.try_into().unwrap(), a, residues)?;
+        self.constrain_binary_crt(ctx, "div_incomplete", &t.try_into().unwrap(), a, residues)?;
 
         // Constrain native part of crt
         main_gate.apply(
@@ -0,0 +1,66 @@
+use super::{IntegerChip, IntegerInstructions};
+use crate::{AssignedInteger, PrimeField};
+use halo2::plonk::Error;
+use maingate::{halo2, AssignedCondition, MainGateInstructions, RegionCtx};
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Number of division steps that is enough for Euclidean algorithm to
+    /// terminate for any pair of inputs smaller than the wrong modulus. By
+    /// Lamé's theorem `k` steps require the smaller input to be at least
+    /// `F_(k+1) >= phi^(k-1)`, so for `n` bit inputs
+    /// `k <= n / log2(phi) + 1 < 1.441 * n + 1`.
+    ///
+    /// Every step is synthesized, so the bound is also the constraint cost of
+    /// [`IntegerInstructions::gcd`]: 370 `reduce_mod_assigned` steps for a
+    /// 256 bit wrong field.
+    pub(crate) fn gcd_iteration_bound() -> usize {
+        (W::NUM_BITS as usize * 1441) / 1000 + 2
+    }
+
+    /// Returns `1` if all limbs of `a` are zero
+    fn is_limbs_zero(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedCondition<N>, Error> {
+        let main_gate = self.main_gate();
+        let mut is_zero = main_gate.is_zero(ctx, a.limb(0))?;
+        for i in 1..NUMBER_OF_LIMBS {
+            let is_limb_zero = main_gate.is_zero(ctx, a.limb(i))?;
+            is_zero = main_gate.and(ctx, &is_zero, &is_limb_zero)?;
+        }
+        Ok(is_zero)
+    }
+
+    pub(super) fn gcd_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        // Work with canonical representatives in `[0, p)`
+        let a = self.reduce(ctx, a)?;
+        self.assert_in_field(ctx, &a)?;
+        let b = self.reduce(ctx, b)?;
+        self.assert_in_field(ctx, &b)?;
+
+        let one = &self.assign_constant(ctx, W::ONE)?;
+
+        // Each step is `(x, y) -> (y, x mod y)` where quotient and remainder
+        // are range checked in `reduce_mod_assigned`. Once `y` hits zero the
+        // step turns into `(x, 0) -> (x, x mod 1)` which keeps the state
+        let (mut x, mut y) = (a, b);
+        for _ in 0..Self::gcd_iteration_bound() {
+            let is_y_zero = &self.is_limbs_zero(ctx, &y)?;
+            let divisor = &self.select(ctx, one, &y, is_y_zero)?;
+            let remainder = self.reduce_mod_assigned(ctx, &x, divisor)?;
+            x = self.select(ctx, &x, &y, is_y_zero)?;
+            y = remainder;
+        }
+        self.assert_strict_zero(ctx, &y)?;
+
+        Ok(x)
+    }
+}
@@ -46,6 +46,12 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         // 2. (a_mul_inv[0] - 1) * (inv_or_one[0] - 1) = 0
         main_gate.one_or_one(ctx, a_mul_inv.limb(0), inv_or_one.native())?;
         main_gate.one_or_one(ctx, a_mul_inv.limb(0), inv_or_one.limb(0))?;
+        for inv in [inv_or_one.native(), inv_or_one.limb(0)] {
+            self.record_relation("invert", "one_or_one", |r| {
+                let (x, y) = (r.variable(a_mul_inv.limb(0)), r.variable(inv));
+                format!("({x} - 1) * ({y} - 1)")
+            });
+        }
 
         // Align with main_gain.invert(), cond = 1 - a_mul_inv
         let cond = a_mul_inv
@@ -67,6 +73,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                 CombinationOptionCommon::OneLinerMul.into(),
             )?
             .swap_remove(1);
+        self.record_relation("invert", "condition", |r| {
+            let (x, cond) = (r.variable(a_mul_inv.limb(0)), r.variable(&cond));
+            format!("{x} + {cond} + {x} * {cond} - 1")
+        });
 
         Ok((inv_or_one, cond))
     }
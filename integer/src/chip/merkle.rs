@@ -0,0 +1,64 @@
+use super::{IntegerChip, IntegerInstructions};
+use crate::instructions::HashInstructions;
+use crate::{AssignedInteger, PrimeField};
+use halo2::plonk::Error;
+use maingate::{
+    big_to_fe, halo2, AssignedCondition, AssignedValue, MainGateInstructions, RegionCtx, Term,
+};
+use num_bigint::BigUint as big_uint;
+use num_traits::One;
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub(super) fn pack_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedValue<N>>, Error> {
+        // Canonical form makes packing injective over the wrong field
+        let a = &self.reduce(ctx, a)?;
+        self.assert_in_field(ctx, a)?;
+
+        // Reduced limbs are range checked to limb bit length so a packed value
+        // doesn't wrap around the native modulus
+        let limbs_per_value = (N::NUM_BITS as usize - 1) / BIT_LEN_LIMB;
+        assert!(limbs_per_value > 0);
+        let bases = (0..limbs_per_value)
+            .map(|i| big_to_fe(big_uint::one() << (i * BIT_LEN_LIMB)))
+            .collect::<Vec<N>>();
+
+        a.limbs()
+            .chunks(limbs_per_value)
+            .map(|limbs| {
+                let terms = limbs
+                    .iter()
+                    .zip(bases.iter())
+                    .map(|(limb, base)| Term::Assigned(limb.as_ref(), *base))
+                    .collect::<Vec<Term<N>>>();
+                self.main_gate().compose(ctx, &terms, N::ZERO)
+            })
+            .collect()
+    }
+
+    pub(super) fn assert_merkle_member_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        path: &[(AssignedValue<N>, AssignedCondition<N>)],
+        root: &AssignedValue<N>,
+        hasher: &dyn HashInstructions<N>,
+    ) -> Result<(), Error> {
+        let main_gate = self.main_gate();
+
+        let packed = self.pack(ctx, a)?;
+        let mut node = hasher.hash(ctx, &packed)?;
+        for (sibling, sibling_is_left) in path {
+            let left = main_gate.select(ctx, sibling, &node, sibling_is_left)?;
+            let right = main_gate.select(ctx, &node, sibling, sibling_is_left)?;
+            node = hasher.hash(ctx, &[left, right])?;
+        }
+
+        main_gate.assert_equal(ctx, &node, root)
+    }
+}
@@ -3,8 +3,8 @@ use crate::rns::{Common, Integer, MaybeReduced};
 use crate::{AssignedInteger, PrimeField};
 use halo2::{arithmetic::Field, plonk::Error};
 use maingate::{
-    halo2, AssignedValue, CombinationOptionCommon, MainGateInstructions, RangeInstructions,
-    RegionCtx, Term,
+    fe_to_big, halo2, AssignedValue, CombinationOptionCommon, MainGateInstructions,
+    RangeInstructions, RegionCtx, Term,
 };
 
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
@@ -13,6 +13,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     pub(super) fn constrain_binary_crt(
         &self,
         ctx: &mut RegionCtx<'_, N>,
+        op: &str,
         t: &[AssignedValue<N>; NUMBER_OF_LIMBS],
         result: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
         residues: Vec<AssignedValue<N>>,
@@ -24,6 +25,8 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         let lsh_one = self.rns.left_shifter(1);
         let lsh_two = self.rns.left_shifter(2);
         let mut carry = Term::Zero;
+        let mut carry_var: Option<&AssignedValue<N>> = None;
+        let (lsh_one_big, lsh_two_big) = (fe_to_big(lsh_one), fe_to_big(lsh_two));
 
         for ((t_chunk, r_chunk), v) in t
             .chunks(2)
@@ -46,7 +49,21 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                     ],
                     zero,
                 )?;
+                self.record_relation(op, "residue", |rec| {
+                    let carry = carry_var
+                        .map(|carry| format!(" + {}", rec.variable(carry)))
+                        .unwrap_or_default();
+                    format!(
+                        "{} + {lsh_one_big} * {} - {} - {lsh_one_big} * {} - {lsh_two_big} * {}{carry}",
+                        rec.variable(t_lo),
+                        rec.variable(t_hi),
+                        rec.variable(r_lo),
+                        rec.variable(r_hi),
+                        rec.variable(v),
+                    )
+                });
                 carry = Term::Assigned(v, one);
+                carry_var = Some(v);
             } else {
                 main_gate.assert_zero_sum(
                     ctx,
@@ -59,11 +76,67 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                     ],
                     zero,
                 )?;
+                self.record_relation(op, "residue", |rec| {
+                    let carry = carry_var
+                        .map(|carry| format!(" + {}", rec.variable(carry)))
+                        .unwrap_or_default();
+                    format!(
+                        "{} + {} - {lsh_one_big} * {}{carry}",
+                        rec.variable(&t_chunk[0]),
+                        rec.variable(r_chunk[0].as_ref()),
+                        rec.variable(v),
+                    )
+                });
             }
         }
         Ok(())
     }
 
+    /// Records intermediate values of the multiplication `x * y = w * q + r`
+    /// where `t_i = sum(x_j * y_k + p_j * q_k)` over `j + k = i` and `p` is
+    /// the negative wrong modulus
+    pub(super) fn record_intermediates(
+        &self,
+        op: &str,
+        t: &[AssignedValue<N>],
+        x: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        y: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        quotient: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) {
+        let negative_wrong_modulus = self.rns.negative_wrong_modulus_decomposed;
+        for (i, t_i) in t.iter().enumerate() {
+            self.record_relation(op, "intermediate", |r| {
+                let sum = (0..=i)
+                    .map(|j| {
+                        let k = i - j;
+                        let p = fe_to_big(negative_wrong_modulus[j]);
+                        let (x_j, y_k) = (r.variable(x.limb(j)), r.variable(y.limb(k)));
+                        format!("{x_j} * {y_k} + {p} * {}", r.variable(quotient.limb(k)))
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" + ");
+                format!("{} - ({sum})", r.variable(t_i))
+            });
+        }
+    }
+
+    /// Records native part of the multiplication `x * y = w * q + r`
+    pub(super) fn record_native_product(
+        &self,
+        op: &str,
+        x: &AssignedValue<N>,
+        y: &AssignedValue<N>,
+        quotient: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        result: &AssignedValue<N>,
+    ) {
+        self.record_relation(op, "native", |r| {
+            let w = fe_to_big(self.rns.wrong_modulus_in_native_modulus);
+            let (x, y) = (r.variable(x), r.variable(y));
+            let (q, result) = (r.variable(quotient.native()), r.variable(result));
+            format!("{x} * {y} - {w} * {q} - {result}")
+        });
+    }
+
     #[allow(clippy::needless_range_loop)]
     pub(super) fn mul_generic(
         &self,
@@ -167,9 +240,17 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             }
         }
 
+        // Record intermediate, native and range relations
+        self.record_intermediates("mul", &t, a, b, quotient);
+        self.record_native_product("mul", a.native(), b.native(), quotient, result.native());
+        for v in residues.iter() {
+            self.record_range("mul", v, self.rns.mul_v_bit_len);
+        }
+
         // Constrain binary part of crt
         self.constrain_binary_crt(
             ctx,
+            "mul",
             &t.try_into()
                 .expect("Unexpected failure in AssignedCell -> AssignedValue conversion"),
             &result,
@@ -190,6 +271,13 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             CombinationOptionCommon::OneLinerMul.into(),
         )?;
 
+        #[cfg(feature = "checksum")]
+        let result = self.derive_reduction_checksum(
+            "mul",
+            result,
+            &[a, b],
+            quotient.integer().map(|quotient| quotient.value()),
+        );
         Ok(result)
     }
 
@@ -240,9 +328,43 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             })
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
 
+        // Record intermediate, native and range relations
+        for (i, t_i) in t.iter().enumerate() {
+            self.record_relation("mul_constant", "intermediate", |r| {
+                let sum = (0..=i)
+                    .map(|j| {
+                        let k = i - j;
+                        let (b_k, p_k) = (
+                            fe_to_big(b.limb(k).fe()),
+                            fe_to_big(negative_wrong_modulus[k]),
+                        );
+                        let (a_j, q_j) = (r.variable(a.limb(j)), r.variable(quotient.limb(j)));
+                        format!("{b_k} * {a_j} + {p_k} * {q_j}")
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" + ");
+                format!("{} - ({sum})", r.variable(t_i))
+            });
+        }
+        self.record_relation("mul_constant", "native", |r| {
+            let (b, w) = (
+                fe_to_big(b.native()),
+                fe_to_big(self.rns.wrong_modulus_in_native_modulus),
+            );
+            let (a_n, q_n) = (r.variable(a.native()), r.variable(quotient.native()));
+            format!(
+                "{b} * {a_n} - {w} * {q_n} - {}",
+                r.variable(result.native())
+            )
+        });
+        for v in residues.iter() {
+            self.record_range("mul_constant", v, self.rns.mul_v_bit_len);
+        }
+
         // Constrain binary part of crt
         self.constrain_binary_crt(
             ctx,
+            "mul_constant",
             &t.try_into()
                 .expect("Unexpected failure in AssignedCell -> AssignedValue conversion"),
             &result,
@@ -350,10 +472,26 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             }
         }
 
+        // Record intermediate, native and range relations
+        self.record_intermediates("mul_into_one", &t, a, b, quotient);
+        self.record_relation("mul_into_one", "native", |r| {
+            let w = fe_to_big(self.rns.wrong_modulus_in_native_modulus);
+            let (a_n, b_n) = (r.variable(a.native()), r.variable(b.native()));
+            format!(
+                "{a_n} * {b_n} - {w} * {} - 1",
+                r.variable(quotient.native())
+            )
+        });
+        for v in residues.iter() {
+            self.record_range("mul_into_one", v, self.rns.mul_v_bit_len);
+        }
+
         // Constrain binary part of crt
         let lsh_one = self.rns.left_shifter(1);
         let lsh_two = self.rns.left_shifter(2);
         let mut carry = Term::Zero;
+        let mut carry_var: Option<&AssignedValue<N>> = None;
+        let (lsh_one_big, lsh_two_big) = (fe_to_big(lsh_one), fe_to_big(lsh_two));
 
         for (i, (t_chunk, v)) in t.chunks(2).zip(residues.iter()).enumerate() {
             if t_chunk.len() == 2 {
@@ -369,9 +507,22 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                     ],
                     if i == 0 { -one } else { zero },
                 )?;
+                self.record_relation("mul_into_one", "residue", |rec| {
+                    let carry = carry_var
+                        .map(|carry| format!(" + {}", rec.variable(carry)))
+                        .unwrap_or_default();
+                    let constant = if i == 0 { " - 1" } else { "" };
+                    format!(
+                        "{} + {lsh_one_big} * {} - {lsh_two_big} * {}{carry}{constant}",
+                        rec.variable(t_lo),
+                        rec.variable(t_hi),
+                        rec.variable(v),
+                    )
+                });
                 carry = Term::Assigned(v, one);
+                carry_var = Some(v);
             } else {
-                let t = &t[0];
+                let t = &t_chunk[0];
                 main_gate.assert_zero_sum(
                     ctx,
                     &[
@@ -382,6 +533,16 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                     ],
                     zero,
                 )?;
+                self.record_relation("mul_into_one", "residue", |rec| {
+                    let carry = carry_var
+                        .map(|carry| format!(" + {}", rec.variable(carry)))
+                        .unwrap_or_default();
+                    format!(
+                        "{} - {lsh_one_big} * {}{carry}",
+                        rec.variable(t),
+                        rec.variable(v),
+                    )
+                });
             }
         }
 
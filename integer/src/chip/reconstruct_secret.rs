@@ -0,0 +1,53 @@
+use super::{IntegerChip, IntegerInstructions};
+use crate::rns::{Common, Integer};
+use crate::{AssignedInteger, PrimeField};
+use halo2::plonk::Error;
+use maingate::{big_to_fe, halo2, RegionCtx};
+use std::rc::Rc;
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Lagrange basis coefficients `L_i(0) = prod_{j != i} x_j / (x_j - x_i)`
+    /// for the given evaluation points. Returns `None` if points are not
+    /// distinct.
+    fn lagrange_coefficients_at_zero(xs: &[W]) -> Option<Vec<W>> {
+        xs.iter()
+            .enumerate()
+            .map(|(i, x_i)| {
+                let (numerator, denominator) = xs.iter().enumerate().filter(|(j, _)| *j != i).fold(
+                    (W::ONE, W::ONE),
+                    |(numerator, denominator), (_, x_j)| {
+                        (numerator * x_j, denominator * (*x_j - x_i))
+                    },
+                );
+                Option::<W>::from(denominator.invert()).map(|inv| numerator * inv)
+            })
+            .collect()
+    }
+
+    #[allow(clippy::type_complexity)]
+    pub(super) fn reconstruct_secret_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        shares: &[(
+            Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let xs: Vec<W> = shares.iter().map(|(x, _)| big_to_fe(x.value())).collect();
+        let coeffs = Self::lagrange_coefficients_at_zero(&xs).ok_or(Error::Synthesis)?;
+
+        // secret = sum_i y_i * L_i(0)
+        let mut secret: Option<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>> = None;
+        for ((_, y), coeff) in shares.iter().zip(coeffs) {
+            let coeff = Integer::from_fe(coeff, Rc::clone(&self.rns));
+            let term = self.mul_constant(ctx, y, &coeff)?;
+            secret = Some(match secret {
+                Some(secret) => self.add(ctx, &secret, &term)?,
+                None => term,
+            });
+        }
+        secret.ok_or(Error::Synthesis)
+    }
+}
@@ -1,8 +1,10 @@
-use super::{IntegerChip, IntegerInstructions, Range};
+use super::{IntegerChip, IntegerInstructions, Range, ReductionPolicy};
 use crate::rns::MaybeReduced;
 use crate::{AssignedInteger, PrimeField};
 use halo2::plonk::Error;
-use maingate::{halo2, AssignedValue, MainGateInstructions, RangeInstructions, RegionCtx, Term};
+use maingate::{
+    fe_to_big, halo2, AssignedValue, MainGateInstructions, RangeInstructions, RegionCtx, Term,
+};
 
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
     IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
@@ -36,7 +38,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         if exceeds_max_limb_value {
             self.reduce(ctx, a)
         } else {
-            Ok(self.new_assigned_integer(a.limbs(), a.native().clone()))
+            Ok(a.clone())
         }
     }
 
@@ -54,7 +56,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         if exceeds_max_limb_value {
             self.reduce(ctx, a)
         } else {
-            Ok(self.new_assigned_integer(a.limbs(), a.native().clone()))
+            Ok(a.clone())
         }
     }
 
@@ -69,7 +71,54 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         if exceeds_max_value {
             self.reduce(ctx, a)
         } else {
-            Ok(self.new_assigned_integer(a.limbs(), a.native().clone()))
+            Ok(a.clone())
+        }
+    }
+
+    /// Reduces an [`AssignedInteger`] if any of its limbs values is greater
+    /// than the [`Rns`] `max_reduced_limb` or its value is greater than the
+    /// [`Rns`] `max_remainder`
+    pub(super) fn reduce_if_max_remainder_value_exceeds(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let a = self.reduce_if_limb_values_exceeds_reduced(ctx, a)?;
+        if a.max_val() > self.rns.max_remainder {
+            self.reduce(ctx, &a)
+        } else {
+            Ok(a)
+        }
+    }
+
+    /// Reduces result of an additive operation if the chip follows
+    /// [`ReductionPolicy::Eager`]
+    pub(super) fn apply_policy(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        match self.policy {
+            ReductionPolicy::Lazy => Ok(a),
+            ReductionPolicy::Eager => self.reduce(ctx, &a),
+        }
+    }
+
+    /// Records intermediate values `t_i = a_i + p_i * q` of a reduction with
+    /// the short quotient `q` where `p` is the negative wrong modulus
+    pub(super) fn record_reduction_intermediates(
+        &self,
+        op: &str,
+        t: &[AssignedValue<N>],
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        quotient: &AssignedValue<N>,
+    ) {
+        let negative_wrong_modulus = self.rns.negative_wrong_modulus_decomposed;
+        for ((t_i, a_i), p_i) in t.iter().zip(a.limbs()).zip(negative_wrong_modulus) {
+            self.record_relation(op, "intermediate", |r| {
+                let (a_i, q) = (r.variable(a_i.as_ref()), r.variable(quotient));
+                format!("{} - {a_i} - {} * {q}", r.variable(t_i), fe_to_big(p_i))
+            });
         }
     }
 
@@ -78,6 +127,8 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        self.reductions.set(self.reductions.get() + 1);
+
         let main_gate = self.main_gate();
         let (zero, one) = (N::ZERO, N::ONE);
 
@@ -95,6 +146,10 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             .iter()
             .map(|v| range_chip.assign(ctx, *v, Self::sublimb_bit_len(), self.rns.red_v_bit_len))
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+        self.record_range("reduce", &quotient, BIT_LEN_LIMB);
+        for v in residues.iter() {
+            self.record_range("reduce", v, self.rns.red_v_bit_len);
+        }
 
         // Assign intermediate values
         let t: Vec<AssignedValue<N>> = a
@@ -112,10 +167,17 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
                 )
             })
             .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+        self.record_reduction_intermediates("reduce", &t, a, &quotient);
+        self.record_relation("reduce", "native", |r| {
+            let w = fe_to_big(self.rns.wrong_modulus_in_native_modulus);
+            let (a_n, q) = (r.variable(a.native()), r.variable(&quotient));
+            format!("{} + {w} * {q} - {a_n}", r.variable(result.native()))
+        });
 
         // Constrain binary part of crt
         self.constrain_binary_crt(
             ctx,
+            "reduce",
             &t.try_into()
                 .expect("Unexpected failure in AssignedCell -> AssignedValue conversion"),
             &result,
@@ -133,6 +195,11 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             zero,
         )?;
 
+        #[cfg(feature = "checksum")]
+        let result = {
+            let quotient = quotient.value().map(|quotient| fe_to_big(*quotient));
+            self.derive_reduction_checksum("reduce", result, &[a], quotient)
+        };
         Ok(result)
     }
 }
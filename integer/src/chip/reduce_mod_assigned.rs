@@ -0,0 +1,275 @@
+use super::{IntegerChip, Range};
+use crate::rns::{Common, Integer, MaybeReduced};
+use crate::{AssignedInteger, PrimeField};
+use halo2::{arithmetic::Field, circuit::Value, plonk::Error};
+use maingate::{
+    halo2, AssignedValue, CombinationOptionCommon, MainGateInstructions, RangeInstructions,
+    RegionCtx, Term,
+};
+use num_bigint::BigUint as big_uint;
+use num_traits::Zero;
+use std::rc::Rc;
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub(super) fn reduce_mod_assigned_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        // Inputs cannot be reduced by the wrong modulus without changing their
+        // values. Staying in operand range guarantees that `q * p + r` side of the
+        // equation doesn't wrap `crt_modulus`.
+        let a = &self.normalize(ctx, a)?;
+        let p = &self.normalize(ctx, p)?;
+
+        let main_gate = self.main_gate();
+        let (zero, one) = (N::ZERO, N::ONE);
+
+        let witness: MaybeReduced<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> = a
+            .integer()
+            .zip(p.integer())
+            .map(|(a_int, p_int)| a_int.reduce_by(&p_int))
+            .into();
+        let result = witness.result();
+        let quotient = witness.long();
+
+        // Apply ranges
+        let range_chip = self.range_chip();
+        let result = self.assign_integer_generic(ctx, result.into(), Range::Operand)?;
+        let quotient = &self.assign_integer_generic(ctx, quotient.into(), Range::Operand)?;
+        let residues = witness
+            .residues()
+            .iter()
+            .map(|v| range_chip.assign(ctx, *v, Self::sublimb_bit_len(), self.rns.mul_v_bit_len))
+            .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+
+        // Witness layout for `NUMBER_OF_LIMBS = 4`:
+        // | A   | B   | C   | D     |
+        // | --- | --- | --- | ----- |
+        // | q_0 | p_0 | r_0 | t_0   |
+
+        // | q_0 | p_1 | r_1 | t_1   |
+        // | q_1 | p_0 | -   | tmp   |
+
+        // | q_0 | p_2 | r_2 | t_2   |
+        // | q_1 | p_1 | -   | tmp_a |
+        // | q_2 | p_0 | -   | tmp_b |
+
+        // | q_0 | p_3 | r_3 | t_3   |
+        // | q_1 | p_2 | -   | tmp_b |
+        // | q_2 | p_1 | -   | tmp_a |
+        // | q_3 | p_0 | -   | tmp_c |
+
+        let mut t: Vec<AssignedValue<N>> = vec![];
+
+        // Assign intermediate values
+        for (i, intermediate_value) in witness.intermediates().into_iter().enumerate() {
+            let mut intermediate_value = intermediate_value;
+
+            for j in 0..=i {
+                let k = i - j;
+
+                let combination_option = if k == 0 {
+                    CombinationOptionCommon::OneLinerMul
+                } else {
+                    CombinationOptionCommon::CombineToNextMul(one)
+                }
+                .into();
+
+                let t_i = main_gate
+                    .apply(
+                        ctx,
+                        [
+                            Term::Assigned(quotient.limb(j), zero),
+                            Term::Assigned(p.limb(k), zero),
+                            if j == 0 {
+                                Term::Assigned(result.limb(i), one)
+                            } else {
+                                Term::Zero
+                            },
+                            Term::Zero,
+                            Term::Unassigned(intermediate_value, -one),
+                        ],
+                        zero,
+                        combination_option,
+                    )?
+                    .swap_remove(4);
+
+                if j == 0 {
+                    // first time we see t_j assignment
+                    t.push(t_i);
+                }
+
+                // update running temp value
+                intermediate_value = intermediate_value
+                    .zip(quotient.limb(j).value())
+                    .zip(p.limb(k).value())
+                    .zip(result.limb(i).value())
+                    .map(|(((t, q), p), r)| {
+                        let r = if j == 0 { *r } else { zero };
+                        t - (*q * *p + r)
+                    });
+
+                // Sanity check for the last running subtraction value
+                {
+                    if j == i {
+                        intermediate_value.assert_if_known(Field::is_zero_vartime);
+                    }
+                }
+            }
+        }
+
+        // Constrain binary part of crt
+        self.constrain_binary_crt(
+            ctx,
+            "reduce_mod_assigned",
+            &t.try_into()
+                .expect("Unexpected failure in AssignedCell -> AssignedValue conversion"),
+            a,
+            residues,
+        )?;
+
+        // Constrain native part of crt
+        main_gate.apply(
+            ctx,
+            [
+                Term::Assigned(quotient.native(), zero),
+                Term::Assigned(p.native(), zero),
+                Term::Assigned(result.native(), one),
+                Term::Zero,
+                Term::Assigned(a.native(), -one),
+            ],
+            zero,
+            CombinationOptionCommon::OneLinerMul.into(),
+        )?;
+
+        // Remainder must be less than the modulus which also rejects zero modulus
+        self.assert_less_than(ctx, &result, p)?;
+
+        Ok(result)
+    }
+
+    /// Returns an integer with the same value as `a` whose limbs are in
+    /// operand range. Overflowing limbs of lazily computed integers are
+    /// carried to the next limb with `a_i + c_{i-1} = n_i + c_i * R`. Fails if
+    /// maximum value of `a` exceeds the maximum operand.
+    fn normalize(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        if a.max_val() > self.rns.max_operand {
+            return Err(Error::Synthesis);
+        }
+        if a.limbs
+            .iter()
+            .all(|limb| limb.max_val() <= self.rns.max_reduced_limb)
+        {
+            return Ok(a.clone());
+        }
+
+        let main_gate = self.main_gate();
+        let range_chip = self.range_chip();
+        let (zero, one) = (N::ZERO, N::ONE);
+        let (lsh, rsh) = (self.rns.left_shifter(1), self.rns.right_shifter(1));
+        let sublimb_bit_len = Self::sublimb_bit_len();
+
+        let normalized = a
+            .integer()
+            .map(|a| Integer::from_big(a.value(), Rc::clone(&self.rns)));
+        let normalized = self.assign_integer_generic(ctx, normalized.into(), Range::Operand)?;
+
+        let mut carry: Option<AssignedValue<N>> = None;
+        let mut max_carry = big_uint::zero();
+        for (i, limb) in a.limbs.iter().enumerate() {
+            let carry_value = carry
+                .as_ref()
+                .map(|carry| carry.value().cloned())
+                .unwrap_or_else(|| Value::known(zero));
+            let next = if i != NUMBER_OF_LIMBS - 1 {
+                max_carry = (limb.max_val() + &max_carry) >> BIT_LEN_LIMB;
+                let bit_len =
+                    (max_carry.bits() as usize).div_ceil(sublimb_bit_len).max(1) * sublimb_bit_len;
+                let next = limb
+                    .value()
+                    .zip(normalized.limb(i).value())
+                    .zip(carry_value)
+                    .map(|((a, n), carry)| (a + carry - n) * rsh);
+                Some(range_chip.assign(ctx, next, sublimb_bit_len, bit_len)?)
+            } else {
+                None
+            };
+
+            let mut terms = vec![
+                Term::Assigned(limb.as_ref(), one),
+                Term::Assigned(normalized.limb(i), -one),
+            ];
+            if let Some(carry) = &carry {
+                terms.push(Term::Assigned(carry, one));
+            }
+            if let Some(next) = &next {
+                terms.push(Term::Assigned(next, -lsh));
+            }
+            main_gate.assert_zero_sum(ctx, &terms, zero)?;
+
+            carry = next;
+        }
+
+        Ok(normalized)
+    }
+
+    /// Constrains `a < b` where both integers are compared as they are, that is
+    /// not under any modulus. Limbs of both integers are expected to be
+    /// reduced.
+    #[allow(clippy::needless_range_loop)]
+    fn assert_less_than(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error> {
+        // Constraints for `NUMBER_OF_LIMBS = 4`
+        // 0 = -c_0 + b_0 - a_0 - 1 + br_0 * R
+        // 0 = -c_1 + b_1 - a_1 + br_1 * R - br_0
+        // 0 = -c_2 + b_2 - a_2 + br_2 * R - br_1
+        // 0 = -c_3 + b_3 - a_3            - br_2
+
+        let main_gate = self.main_gate();
+        let one = N::ONE;
+
+        // result is `b - a - 1`, borrow values must be bits and result limbs must be
+        // in range
+        let comparision_witness = a.integer().zip(b.integer()).map(|(a, b)| a.compare_to(&b));
+        let result = comparision_witness.as_ref().map(|r| r.result.clone());
+        let result = &self.assign_integer_generic(ctx, result.into(), Range::Operand)?;
+
+        let borrow = comparision_witness.as_ref().map(|r| r.borrow);
+        let borrow = (0..NUMBER_OF_LIMBS - 1)
+            .map(|i| {
+                let b_i = borrow.map(|borrow| if borrow[i] { N::ONE } else { N::ZERO });
+                main_gate.assign_bit(ctx, b_i)
+            })
+            .collect::<Result<Vec<AssignedValue<N>>, Error>>()?;
+
+        let left_shifter = self.rns.left_shifter(1);
+        for i in 0..NUMBER_OF_LIMBS {
+            let mut terms = vec![
+                Term::Assigned(result.limb(i), -one),
+                Term::Assigned(b.limb(i), one),
+                Term::Assigned(a.limb(i), -one),
+            ];
+            if i != NUMBER_OF_LIMBS - 1 {
+                terms.push(Term::Assigned(&borrow[i], left_shifter));
+            }
+            if i != 0 {
+                terms.push(Term::Assigned(&borrow[i - 1], -one));
+            }
+            main_gate.assert_zero_sum(ctx, &terms, if i == 0 { -one } else { N::ZERO })?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,30 @@
+use super::{IntegerChip, IntegerInstructions};
+use crate::{AssignedInteger, PrimeField};
+use halo2::plonk::Error;
+use maingate::{halo2, RegionCtx};
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    pub(super) fn rlc_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        values: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        challenge: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        // acc = ((v_(n-1) * c + v_(n-2)) * c + ... ) * c + v_0
+        //
+        // Additions are left lazy so the accumulator is only reduced when it is
+        // about to be multiplied, and the final sum is returned unreduced.
+        let mut values = values.iter().rev();
+        let mut acc = match values.next() {
+            Some(value) => value.clone(),
+            None => return self.assign_constant(ctx, W::ZERO),
+        };
+        for value in values {
+            let acc_mul_challenge = &self.mul(ctx, &acc, challenge)?;
+            acc = self.add(ctx, acc_mul_challenge, value)?;
+        }
+        Ok(acc)
+    }
+}
@@ -0,0 +1,86 @@
+use super::IntegerChip;
+use crate::{AssignedInteger, AssignedLimb, PrimeField, SmallInteger};
+use halo2::circuit::Value;
+use halo2::plonk::Error;
+use maingate::{halo2, MainGateInstructions, RangeInstructions, RegionCtx};
+use num_bigint::BigUint as big_uint;
+use num_traits::{One, Zero};
+use std::rc::Rc;
+
+impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
+    IntegerChip<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>
+{
+    /// Returns `max_val` as a small integer bound or fails if it exceeds a
+    /// limb
+    fn small_max_val(max_val: big_uint) -> Result<big_uint, Error> {
+        if max_val.bits() as usize > BIT_LEN_LIMB {
+            return Err(Error::Synthesis);
+        }
+        Ok(max_val)
+    }
+
+    pub(super) fn assign_small_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: Value<N>,
+        bit_len: usize,
+    ) -> Result<SmallInteger<N>, Error> {
+        let max_val = Self::small_max_val((big_uint::one() << bit_len) - 1usize)?;
+        let value = if bit_len == 1 {
+            self.main_gate().assign_bit(ctx, value)?
+        } else {
+            // Range chip only has overflow tables for the lengths that the rns
+            // uses so small integers are made of full sublimbs
+            if bit_len == 0 || bit_len % Self::sublimb_bit_len() != 0 {
+                return Err(Error::Synthesis);
+            }
+            self.range_chip()
+                .assign(ctx, value, Self::sublimb_bit_len(), bit_len)?
+        };
+        Ok(SmallInteger { value, max_val })
+    }
+
+    pub(super) fn add_small_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &SmallInteger<N>,
+        b: &SmallInteger<N>,
+    ) -> Result<SmallInteger<N>, Error> {
+        let max_val = Self::small_max_val(&a.max_val + &b.max_val)?;
+        let value = self.main_gate().add(ctx, &a.value, &b.value)?;
+        Ok(SmallInteger { value, max_val })
+    }
+
+    pub(super) fn mul_small_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &SmallInteger<N>,
+        b: &SmallInteger<N>,
+    ) -> Result<SmallInteger<N>, Error> {
+        let max_val = Self::small_max_val(&a.max_val * &b.max_val)?;
+        let value = self.main_gate().mul(ctx, &a.value, &b.value)?;
+        Ok(SmallInteger { value, max_val })
+    }
+
+    pub(super) fn promote_generic(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &SmallInteger<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error> {
+        let main_gate = self.main_gate();
+        let limbs = (0..NUMBER_OF_LIMBS)
+            .map(|i| {
+                Ok(if i == 0 {
+                    AssignedLimb::from(a.value.clone(), a.max_val.clone())
+                } else {
+                    AssignedLimb::from(main_gate.assign_constant(ctx, N::ZERO)?, big_uint::zero())
+                })
+            })
+            .collect::<Result<Vec<AssignedLimb<N>>, Error>>()?;
+        Ok(AssignedInteger::new(
+            Rc::clone(&self.rns),
+            &limbs.try_into().unwrap(),
+            a.value.clone(),
+        ))
+    }
+}
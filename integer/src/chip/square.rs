@@ -109,8 +109,15 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             }
         }
 
+        // Record intermediate, native and range relations
+        self.record_intermediates("square", &t, a, a, quotient);
+        self.record_native_product("square", a.native(), a.native(), quotient, result.native());
+        for v in residues.iter() {
+            self.record_range("square", v, self.rns.mul_v_bit_len);
+        }
+
         // Constrain binary part of crt
-        self.constrain_binary_crt(ctx, &t.try_into().unwrap(), &result, residues)?;
+        self.constrain_binary_crt(ctx, "square", &t.try_into().unwrap(), &result, residues)?;
 
         // Constrain native part of crt
         let native = a.native();
@@ -127,6 +134,12 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             CombinationOptionCommon::OneLinerMul.into(),
         )?;
 
+        #[cfg(feature = "checksum")]
+        let result = {
+            use crate::rns::Common;
+            let quotient = quotient.integer().map(|quotient| quotient.value());
+            self.derive_reduction_checksum("square", result, &[a, a], quotient)
+        };
         Ok(result)
     }
 }
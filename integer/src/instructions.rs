@@ -1,6 +1,7 @@
-use super::{AssignedInteger, UnassignedInteger};
-use crate::maingate::{halo2, AssignedCondition, RegionCtx};
+use super::{AssignedInteger, SmallInteger, UnassignedInteger};
+use crate::maingate::{halo2, AssignedCondition, AssignedValue, RegionCtx};
 use crate::rns::Integer;
+use halo2::circuit::Value;
 use halo2::plonk::Error;
 use maingate::halo2::halo2curves::ff::PrimeField;
 
@@ -145,6 +146,16 @@ pub trait IntegerInstructions<
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
 
+    /// Divides an [`AssignedInteger`] by a small constant. Inverse of the
+    /// constant is taken from the cache in [`crate::rns::Rns`] and multiplied in.
+    /// Fails with synthesis error if the constant is zero.
+    fn div_by_small_constant(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: u64,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
     /// Divides 2 [`AssignedInteger`]. An [`AssignedCondition`] is returned
     /// along with the division result indicating if the operation was
     /// successful.
@@ -301,4 +312,238 @@ pub trait IntegerInstructions<
         ctx: &mut RegionCtx<'_, N>,
         a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
     ) -> Result<AssignedCondition<N>, Error>;
+
+    /// Computes random linear combination of [`AssignedInteger`]s as
+    /// `sum_i values_i * challenge^i` using Horner's rule. Returns zero for an
+    /// empty input.
+    fn rlc(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        values: &[AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>],
+        challenge: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Reduces an [`AssignedInteger`] by an assigned modulus `p` rather than
+    /// the wrong modulus. Quotient `q` and remainder `r` are witnessed and
+    /// `a = q * p + r` with `r < p` is constrained over integers. Inputs are
+    /// not reduced beforehand, limbs of lazily computed inputs are normalized
+    /// by carrying overflows instead. Returns [`Error::Synthesis`] if the
+    /// maximum value of an input exceeds the operand range.
+    fn reduce_mod_assigned(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        p: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Given x coordinate and a sign bit finds y coordinate of a point on
+    /// `y^2 = x^3 + b` curve. Returned root is the one whose parity matches
+    /// the sign. If `x^3 + b` is not a quadratic residue constraints are not
+    /// satisfied.
+    fn decompress_x(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        x: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        sign: &AssignedCondition<N>,
+        b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Computes greatest common divisor of canonical representatives of two
+    /// [`AssignedInteger`]s. Result is witnessed with Euclidean chain of
+    /// fixed length where each step is a range checked division with
+    /// remainder. Length of the chain is `1.441 * W::NUM_BITS + 2` which by
+    /// Lamé's theorem is enough for any pair of inputs.
+    ///
+    /// The chain is unrolled in full regardless of the inputs, e.g. 370 steps
+    /// for a 256 bit wrong field. Each step is a [`reduce_mod_assigned`],
+    /// which costs more rows than a [`mul`] as it normalizes both inputs and
+    /// range checks both quotient and remainder, followed by a zero test and
+    /// two [`select`]s. A single call therefore costs as much as several
+    /// hundred multiplications and should be kept out of hot paths.
+    ///
+    /// [`reduce_mod_assigned`]: IntegerInstructions::reduce_mod_assigned
+    /// [`mul`]: IntegerInstructions::mul
+    /// [`select`]: IntegerInstructions::select
+    fn gcd(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Asserts that an [`AssignedInteger`] is a root of the monic quadratic
+    /// `x^2 + b*x + c` with constant coefficients.
+    fn assert_quadratic_root(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        x: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error>;
+
+    /// Assigns an integer and opens a commitment to it that is given in the
+    /// public inputs starting at `commitment_instance` row. This chip doesn't
+    /// implement a commitment scheme: committing to the packed canonical form
+    /// of the integer is the caller's job through `committer`, so binding and
+    /// hiding properties are entirely those of the `committer`.
+    fn open_commitment(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: UnassignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        blinding: Value<N>,
+        commitment_instance: usize,
+        committer: &dyn CommitInstructions<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Returns sum of the limbs of an [`AssignedInteger`] in the native field.
+    fn limb_sum(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedValue<N>, Error>;
+
+    /// Reconstructs the secret `f(0)` from shares `(x_i, f(x_i))` with
+    /// Lagrange interpolation. Evaluation points are constants so that basis
+    /// coefficients are folded. Fails with synthesis error if there are no
+    /// shares or evaluation points are not distinct.
+    #[allow(clippy::type_complexity)]
+    fn reconstruct_secret(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        shares: &[(
+            Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        )],
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Returns `[a, a^2, a^4, ..., a^(2^k)]` computed with `k` squarings.
+    fn square_tower(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        k: usize,
+    ) -> Result<Vec<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>, Error>;
+
+    /// Asserts that `cur = a * prev1 + b * prev2` where `a` and `b` are
+    /// constant coefficients of a linear recurrence.
+    fn assert_recurrence(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        prev2: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        prev1: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        cur: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        a: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<(), Error>;
+
+    /// Computes determinant `a*d - b*c` of the 2x2 matrix `[[a, b], [c, d]]`.
+    /// Difference of the products is reduced with a single quotient so
+    /// neither product is reduced on its own.
+    fn det2(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        b: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        d: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Finds `r` such that `r^3 = a`. An [`AssignedCondition`] is returned
+    /// along with the root indicating if `a` is a cubic residue. If it is not,
+    /// the returned root satisfies `r^3 = a * z` or `r^3 = a * z^2` for a
+    /// cubic non residue `z` instead.
+    fn cbrt(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<
+        (
+            AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+            AssignedCondition<N>,
+        ),
+        Error,
+    >;
+
+    /// Assigns a [`SmallInteger`] that is range checked to `bit_len` bits.
+    /// `bit_len` must be either `1` or a multiple of the lookup bit length that
+    /// doesn't exceed the limb bit length.
+    fn assign_small(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        value: Value<N>,
+        bit_len: usize,
+    ) -> Result<SmallInteger<N>, Error>;
+
+    /// Adds two [`SmallInteger`]s in the native field. Fails with synthesis
+    /// error if the result may not fit in a single limb.
+    fn add_small(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &SmallInteger<N>,
+        b: &SmallInteger<N>,
+    ) -> Result<SmallInteger<N>, Error>;
+
+    /// Multiplies two [`SmallInteger`]s in the native field. Fails with
+    /// synthesis error if the result may not fit in a single limb.
+    fn mul_small(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &SmallInteger<N>,
+        b: &SmallInteger<N>,
+    ) -> Result<SmallInteger<N>, Error>;
+
+    /// Promotes a [`SmallInteger`] to an [`AssignedInteger`] where the least
+    /// significant limb and the native value are the small integer itself.
+    fn promote(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &SmallInteger<N>,
+    ) -> Result<AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>, Error>;
+
+    /// Packs limbs of the canonical form of an [`AssignedInteger`] into as few
+    /// native values as possible so that it can be consumed by a hasher
+    fn pack(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> Result<Vec<AssignedValue<N>>, Error>;
+
+    /// Asserts that an [`AssignedInteger`] is a leaf of the Merkle tree with
+    /// the given `root`. Leaf is the hash of the packed integer. Each path
+    /// element is a sibling node and a condition that is set if the sibling
+    /// is on the left.
+    fn assert_merkle_member(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        a: &AssignedInteger<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        path: &[(AssignedValue<N>, AssignedCondition<N>)],
+        root: &AssignedValue<N>,
+        hasher: &dyn HashInstructions<N>,
+    ) -> Result<(), Error>;
+}
+
+/// Hash function over native field elements that [`IntegerInstructions`]
+/// use to commit to integers
+pub trait HashInstructions<N: PrimeField> {
+    /// Hashes given values into a single value
+    fn hash(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        inputs: &[AssignedValue<N>],
+    ) -> Result<AssignedValue<N>, Error>;
+}
+
+/// Commitment scheme over native field elements that the caller supplies to
+/// [`IntegerInstructions::open_commitment`], e.g. Pedersen commitment in a
+/// prime order group
+pub trait CommitInstructions<N: PrimeField> {
+    /// Commits to `values` with `blinding` and returns the commitment as
+    /// native values, e.g. coordinates of a point. Implementations are
+    /// responsible for canonical decomposition of the inputs.
+    fn commit(
+        &self,
+        ctx: &mut RegionCtx<'_, N>,
+        values: &[AssignedValue<N>],
+        blinding: &AssignedValue<N>,
+    ) -> Result<Vec<AssignedValue<N>>, Error>;
 }
@@ -11,8 +11,8 @@ use num_bigint::BigUint as big_uint;
 use rns::Rns;
 use std::rc::Rc;
 
-pub use chip::{IntegerChip, IntegerConfig};
-pub use instructions::{IntegerInstructions, Range};
+pub use chip::{IntegerChip, IntegerChipView, IntegerConfig, ReductionPolicy, SpanCallback};
+pub use instructions::{CommitInstructions, HashInstructions, IntegerInstructions, Range};
 pub use maingate;
 pub use maingate::halo2;
 
@@ -23,9 +23,16 @@ use halo2::halo2curves as curves;
 pub mod chip;
 /// Commoon instructions for integer operations and assignments
 pub mod instructions;
+/// Recording of algebraic relations enforced by integer gadgets
+pub mod relations;
 /// Residue number system construction and utilities
 pub mod rns;
 
+/// Small prime modulus of the debugging checksum that is recorded along with
+/// assigned integers.
+#[cfg(feature = "checksum")]
+pub const CHECKSUM_MODULUS: u64 = (1 << 31) - 1;
+
 /// `RangeChip` supports upto four full limbs decomposition of a value
 /// `AssignedLimb` is mostly subjected to the range check. Say we have 68-bit
 /// limb and it is decomposed to four 17-bit limbs.
@@ -84,28 +91,39 @@ impl<F: PrimeField> AssignedLimb<F> {
     }
 
     fn add(&self, other: &Self) -> big_uint {
-        self.max_val.clone() + &other.max_val
+        Self::checked(self.max_val.clone() + &other.max_val)
     }
 
     fn add_add(&self, other_0: &Self, other_1: &Self) -> big_uint {
-        self.max_val.clone() + &other_0.max_val + &other_1.max_val
+        Self::checked(self.max_val.clone() + &other_0.max_val + &other_1.max_val)
     }
 
     fn mul2(&self) -> big_uint {
-        self.max_val.clone() + &self.max_val
+        Self::checked(self.max_val.clone() + &self.max_val)
     }
 
     fn mul3(&self) -> big_uint {
-        self.max_val.clone() + &self.max_val + &self.max_val
+        Self::checked(self.max_val.clone() + &self.max_val + &self.max_val)
     }
 
     fn add_big(&self, other: big_uint) -> big_uint {
-        self.max_val.clone() + other
+        Self::checked(self.max_val.clone() + other)
     }
 
     fn add_fe(&self, other: F) -> big_uint {
         self.add_big(fe_to_big(other))
     }
+
+    /// In debug builds panics if the maximum value exceeds `2^(native_bits)`
+    /// where limb arithmetic is guaranteed to wrap around the native modulus
+    fn checked(max_val: big_uint) -> big_uint {
+        debug_assert!(
+            max_val <= big_uint::from(1usize) << F::NUM_BITS,
+            "limb max value overflows native field: {}",
+            max_val
+        );
+        max_val
+    }
 }
 
 /// Witness integer that is about to be assigned.
@@ -140,6 +158,9 @@ pub struct AssignedInteger<
     native_value: AssignedValue<N>,
     /// Share rns across all `AssignedIntegers`s
     rns: Rc<Rns<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>>,
+    /// Witness of `value mod CHECKSUM_MODULUS` recorded at assignment
+    #[cfg(feature = "checksum")]
+    checksum: Option<Value<big_uint>>,
 }
 
 impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_LIMB: usize>
@@ -155,6 +176,8 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             limbs: limbs.clone(),
             native_value,
             rns,
+            #[cfg(feature = "checksum")]
+            checksum: None,
         }
     }
 
@@ -218,3 +241,27 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.limbs[idx].as_ref()
     }
 }
+
+/// Small integer that is kept as a single native value. Its maximum value
+/// always fits in a single limb so that arithmetic doesn't wrap around the
+/// native modulus and it can be promoted to an [`AssignedInteger`] without
+/// decomposition.
+#[derive(Debug, Clone)]
+pub struct SmallInteger<F: PrimeField> {
+    // Witness value
+    value: AssignedValue<F>,
+    // Maximum value the witness is known to be bounded by
+    max_val: big_uint,
+}
+
+impl<F: PrimeField> SmallInteger<F> {
+    /// Returns the assigned native value
+    pub fn value(&self) -> &AssignedValue<F> {
+        &self.value
+    }
+
+    /// Returns maximum value of the small integer
+    pub fn max_val(&self) -> &big_uint {
+        &self.max_val
+    }
+}
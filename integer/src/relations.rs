@@ -0,0 +1,148 @@
+use crate::PrimeField;
+use maingate::AssignedValue;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::Write;
+use std::rc::Rc;
+
+/// Algebraic relation that is enforced by an integer gadget. Expression is
+/// written over variables such as `v12` where each variable stands for a
+/// single assigned cell, so relations of composed operations share the
+/// variables of the values they pass to each other. Expressions are
+/// constrained to be zero in the native field except for `range` relations
+/// which are written as `v < 2^k`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Relation {
+    /// Name of the operation that enforces the relation
+    pub op: String,
+    /// Part of the operation that the relation belongs to, e.g. `native`
+    pub kind: String,
+    /// Symbolic expression of the relation
+    pub expression: String,
+}
+
+#[derive(Debug, Default)]
+struct Recorded {
+    relations: Vec<Relation>,
+    // Already recorded relations
+    seen: HashSet<Relation>,
+    // Variable ids keyed by cell, in order of first use
+    variables: BTreeMap<String, usize>,
+    // Operations whose gates are not recorded
+    unrecorded: BTreeSet<String>,
+}
+
+/// Shared sink of [`Relation`]s. Once attached to an
+/// [`crate::IntegerChip`] operations push the relations they enforce so
+/// that they can be exported for external verification.
+#[derive(Clone, Debug, Default)]
+pub struct RelationRecorder(Rc<RefCell<Recorded>>);
+
+impl RelationRecorder {
+    /// Creates an empty recorder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the variable name of the cell of an assigned value. Ids are
+    /// given in order of first use.
+    pub fn variable<F: PrimeField>(&self, value: &AssignedValue<F>) -> String {
+        let cell = format!("{:?}", value.cell());
+        let mut recorded = self.0.borrow_mut();
+        let next = recorded.variables.len();
+        let id = *recorded.variables.entry(cell).or_insert(next);
+        format!("v{id}")
+    }
+
+    /// Appends a new relation. A relation that is already recorded is
+    /// skipped, since floor planners may synthesize a region more than once.
+    pub fn record(&self, op: &str, kind: &str, expression: String) {
+        let relation = Relation {
+            op: op.to_string(),
+            kind: kind.to_string(),
+            expression,
+        };
+        let mut recorded = self.0.borrow_mut();
+        if recorded.seen.insert(relation.clone()) {
+            recorded.relations.push(relation);
+        }
+    }
+
+    /// Marks an operation whose gates are not recorded. Relations of a
+    /// circuit that uses such an operation leave some of its variables
+    /// unconstrained, so the export is flagged as incomplete.
+    pub fn mark_unrecorded(&self, op: &str) {
+        self.0.borrow_mut().unrecorded.insert(op.to_string());
+    }
+
+    /// Returns operations that were used without recording their gates
+    pub fn unrecorded_ops(&self) -> Vec<String> {
+        self.0.borrow().unrecorded.iter().cloned().collect()
+    }
+
+    /// Returns true if every gate of the used operations is recorded
+    pub fn is_complete(&self) -> bool {
+        self.0.borrow().unrecorded.is_empty()
+    }
+
+    /// Returns all relations recorded so far
+    pub fn relations(&self) -> Vec<Relation> {
+        self.0.borrow().relations.clone()
+    }
+
+    /// Exports recorded relations and the cells of their variables as JSON.
+    /// Native modulus is given in decimal so that expressions can be
+    /// interpreted by external tools. Export is marked incomplete and lists
+    /// the operations that are not recorded if there are any.
+    pub fn to_json(&self, native_modulus: &str) -> String {
+        let recorded = self.0.borrow();
+        let mut json = String::new();
+        write!(
+            json,
+            "{{\"native_modulus\":\"{}\",\"complete\":{},\"unrecorded_ops\":[{}],\"variables\":[",
+            native_modulus,
+            recorded.unrecorded.is_empty(),
+            recorded
+                .unrecorded
+                .iter()
+                .map(|op| format!("\"{}\"", escape(op)))
+                .collect::<Vec<_>>()
+                .join(",")
+        )
+        .unwrap();
+        let mut variables = recorded.variables.iter().collect::<Vec<_>>();
+        variables.sort_by_key(|(_, id)| **id);
+        for (i, (cell, id)) in variables.into_iter().enumerate() {
+            if i != 0 {
+                json.push(',');
+            }
+            write!(
+                json,
+                "{{\"name\":\"v{}\",\"cell\":\"{}\"}}",
+                id,
+                escape(cell)
+            )
+            .unwrap();
+        }
+        json.push_str("],\"relations\":[");
+        for (i, relation) in recorded.relations.iter().enumerate() {
+            if i != 0 {
+                json.push(',');
+            }
+            write!(
+                json,
+                "{{\"op\":\"{}\",\"kind\":\"{}\",\"expression\":\"{}\"}}",
+                escape(&relation.op),
+                escape(&relation.kind),
+                escape(&relation.expression)
+            )
+            .unwrap();
+        }
+        json.push_str("]}");
+        json
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
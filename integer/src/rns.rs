@@ -5,6 +5,8 @@ use maingate::{big_to_fe, compose, decompose_big, fe_to_big, halo2, modulus};
 use num_bigint::BigUint as big_uint;
 use num_integer::Integer as _;
 use num_traits::{Num, One, Zero};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::fmt;
 use std::marker::PhantomData;
 use std::rc::Rc;
@@ -155,6 +157,23 @@ pub(crate) struct ComparisionWitness<
     pub(crate) borrow: [bool; NUMBER_OF_LIMBS],
 }
 
+/// Backend for witness only quotient and remainder computation. It is used
+/// by [`Rns`] to derive reduction witnesses and never affects constraints.
+pub trait ReduceBackend: fmt::Debug {
+    /// Returns `(value / modulus, value % modulus)`
+    fn div_rem(&self, value: &big_uint, modulus: &big_uint) -> (big_uint, big_uint);
+}
+
+/// Default [`ReduceBackend`] using long division of `num-bigint`
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BigUintBackend;
+
+impl ReduceBackend for BigUintBackend {
+    fn div_rem(&self, value: &big_uint, modulus: &big_uint) -> (big_uint, big_uint) {
+        value.div_rem(modulus)
+    }
+}
+
 /// Residue Numeral System
 /// Representation of an integer holding its values modulo several coprime
 /// integers.
@@ -224,6 +243,12 @@ pub struct Rns<
     /// circuit.
     pub red_v_bit_len: usize,
 
+    /// Cached inverses of small constants in the wrong field.
+    small_constant_inverses: RefCell<BTreeMap<u64, W>>,
+
+    /// Backend for witness quotient and remainder computation.
+    reduce_backend: Rc<dyn ReduceBackend>,
+
     _marker_wrong: PhantomData<W>,
 }
 
@@ -579,6 +604,9 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
             mul_v_bit_len,
             red_v_bit_len,
 
+            small_constant_inverses: RefCell::new(BTreeMap::new()),
+            reduce_backend: Rc::new(BigUintBackend),
+
             _marker_wrong: PhantomData,
         };
 
@@ -599,6 +627,17 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         rns
     }
 
+    /// Replaces the backend that computes witness quotients and remainders
+    pub fn with_reduce_backend(mut self, backend: impl ReduceBackend + 'static) -> Self {
+        self.reduce_backend = Rc::new(backend);
+        self
+    }
+
+    /// Computes `(value / divisor, value % divisor)` with the reduce backend
+    pub(crate) fn div_rem(&self, value: &big_uint, divisor: &big_uint) -> (big_uint, big_uint) {
+        self.reduce_backend.div_rem(value, divisor)
+    }
+
     /// Right shifters by limb size
     pub fn right_shifter(&self, i: usize) -> N {
         self.right_shifters[i]
@@ -609,6 +648,20 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         self.left_shifters[i]
     }
 
+    /// Returns inverse of a small constant `c` in the wrong field or `None` if
+    /// `c` is zero. Inverses are computed once and cached.
+    pub fn small_constant_inverse(&self, c: u64) -> Option<W> {
+        let cached = self.small_constant_inverses.borrow().get(&c).copied();
+        if cached.is_some() {
+            return cached;
+        }
+        let inverse: Option<W> = W::from(c).invert().into();
+        if let Some(inverse) = inverse {
+            self.small_constant_inverses.borrow_mut().insert(c, inverse);
+        }
+        inverse
+    }
+
     /// Computes the overflow that each component of the [`Rns`] must support.
     // TODO: consider soundness of only single overflow length
     pub fn overflow_lengths(&self) -> Vec<usize> {
@@ -791,7 +844,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
     ) -> ReductionWitness<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
         let modulus = self.rns.wrong_modulus.clone();
         let negative_modulus = self.rns.negative_wrong_modulus_decomposed;
-        let (quotient, result) = (self.value() * other.value()).div_rem(&modulus);
+        let (quotient, result) = self.rns.div_rem(&(self.value() * other.value()), &modulus);
         let quotient = Self::from_big(quotient, Rc::clone(&self.rns));
         let result = Self::from_big(result, Rc::clone(&self.rns));
 
@@ -817,6 +870,46 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         }
     }
 
+    /// Computes the witness values for `self * d - b * c` that is reduced
+    /// with a single quotient. `aux` is a multiple of the wrong modulus that
+    /// keeps the difference positive and `aux_limbs` is its representation
+    /// that is added to the intermediate values.
+    pub(crate) fn det2(
+        &self,
+        b: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        c: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        d: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+        aux: &big_uint,
+        aux_limbs: &[N; NUMBER_OF_LIMBS],
+    ) -> ReductionWitness<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        let modulus = self.rns.wrong_modulus.clone();
+        let negative_modulus = self.rns.negative_wrong_modulus_decomposed;
+        let value = self.value() * d.value() + aux - b.value() * c.value();
+        let (quotient, result) = self.rns.div_rem(&value, &modulus);
+        let quotient = Self::from_big(quotient, Rc::clone(&self.rns));
+        let result = Self::from_big(result, Rc::clone(&self.rns));
+
+        let l = NUMBER_OF_LIMBS;
+        let mut t: Vec<N> = aux_limbs.to_vec();
+        for k in 0..l {
+            for i in 0..=k {
+                let j = k - i;
+                t[i + j] = t[i + j] + self.limb(i).0 * d.limb(j).0 - b.limb(i).0 * c.limb(j).0
+                    + negative_modulus[i] * quotient.limb(j).0;
+            }
+        }
+
+        let t = t.try_into().unwrap();
+        let residues = result.residues(&t);
+
+        ReductionWitness {
+            result,
+            intermediate: t,
+            quotient: Quotient::Long(quotient),
+            residues,
+        }
+    }
+
     // Returns division witnesses
     pub(crate) fn div(
         &self,
@@ -827,12 +920,15 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         // self + w * quotient = other * result
 
         let modulus = &self.rns.wrong_modulus.clone();
-        let result = &(other.invert().unwrap().value() * self.value() % &self.rns.wrong_modulus);
+        let (_, result) = self
+            .rns
+            .div_rem(&(other.invert().unwrap().value() * self.value()), modulus);
+        let result = &result;
 
         let tmp = &(other.value() * result);
         let negative_modulus = self.rns.negative_wrong_modulus_decomposed;
-        let (quotient, reduced_self) = tmp.div_rem(modulus);
-        let (k, must_be_zero) = (self.value() - &reduced_self).div_rem(modulus);
+        let (quotient, reduced_self) = self.rns.div_rem(tmp, modulus);
+        let (k, must_be_zero) = self.rns.div_rem(&(self.value() - &reduced_self), modulus);
         assert_eq!(must_be_zero, big_uint::zero());
         let quotient = Self::from_big(quotient - &k, Rc::clone(&self.rns));
         let result = Self::from_big(result.clone(), Rc::clone(&self.rns));
@@ -864,7 +960,7 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         let modulus = self.rns.wrong_modulus.clone();
         let negative_modulus = self.rns.negative_wrong_modulus_decomposed;
 
-        let (quotient, result) = self.value().div_rem(&modulus);
+        let (quotient, result) = self.rns.div_rem(&self.value(), &modulus);
         assert!(quotient < big_uint::one() << BIT_LEN_LIMB);
 
         let quotient: N = big_to_fe(quotient);
@@ -888,6 +984,42 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         }
     }
 
+    /// Computes the witness values for reduction by an arbitrary divisor as
+    /// `self = divisor * quotient + result` over integers. If the divisor is
+    /// zero the quotient is set to zero and the result to `self`, which is
+    /// not expected to pass `result < divisor` check.
+    pub(crate) fn reduce_by(
+        &self,
+        divisor: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> ReductionWitness<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        let (quotient, result) = if divisor.value().is_zero() {
+            (big_uint::zero(), self.value())
+        } else {
+            self.rns.div_rem(&self.value(), &divisor.value())
+        };
+        let quotient = Self::from_big(quotient, Rc::clone(&self.rns));
+        let result = Self::from_big(result, Rc::clone(&self.rns));
+
+        let l = NUMBER_OF_LIMBS;
+        let mut t: Vec<N> = result.limbs();
+        for k in 0..l {
+            for i in 0..=k {
+                let j = k - i;
+                t[i + j] = t[i + j] + quotient.limb(i).0 * divisor.limb(j).0;
+            }
+        }
+
+        let t = t.try_into().unwrap();
+        let residues = self.residues(&t);
+
+        ReductionWitness {
+            result,
+            intermediate: t,
+            quotient: Quotient::Long(quotient),
+            residues,
+        }
+    }
+
     fn residues(&self, t: &[N; NUMBER_OF_LIMBS]) -> Vec<N> {
         let is_odd = NUMBER_OF_LIMBS & 1 == 1;
         let u_len = (NUMBER_OF_LIMBS + 1) / 2;
@@ -947,6 +1079,37 @@ impl<W: PrimeField, N: PrimeField, const NUMBER_OF_LIMBS: usize, const BIT_LEN_L
         ComparisionWitness { result, borrow }
     }
 
+    // Returns comparision witnesses for `other - self - 1` which is only
+    // expected to be in range if `self < other`
+    pub(crate) fn compare_to(
+        &self,
+        other: &Integer<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB>,
+    ) -> ComparisionWitness<W, N, NUMBER_OF_LIMBS, BIT_LEN_LIMB> {
+        let mut borrow = [false; NUMBER_OF_LIMBS];
+        let mut limbs = [N::ZERO; NUMBER_OF_LIMBS];
+
+        // Subtraction of one is carried in as the initial borrow
+        let mut prev_borrow = big_uint::one();
+        for (i, res_limb) in limbs.iter_mut().enumerate() {
+            let limb = self.limb(i).value();
+            let other_limb = other.limb(i).value();
+            if i == NUMBER_OF_LIMBS - 1 {
+                // Most significant limb cannot borrow. Result wraps in the native field
+                // if `self >= other` and fails the range check
+                *res_limb = big_to_fe::<N>(other_limb) - big_to_fe::<N>(limb + prev_borrow.clone());
+            } else {
+                let cur_borrow = other_limb < &limb + &prev_borrow;
+                borrow[i] = cur_borrow;
+                let cur_borrow = bool_to_big(cur_borrow) << BIT_LEN_LIMB;
+                *res_limb = big_to_fe(((other_limb + cur_borrow) - prev_borrow.clone()) - limb);
+                prev_borrow = bool_to_big(borrow[i]);
+            }
+        }
+
+        let result = Integer::from_limbs(&limbs, Rc::clone(&self.rns));
+        ComparisionWitness { result, borrow }
+    }
+
     /// Construct a new integer that equals to the modulus and its max limb
     /// values are higher than the given max values
     pub(crate) fn subtracion_aux(
@@ -439,6 +439,23 @@ impl<F: PrimeField> MainGate<F> {
         }
     }
 
+    /// Assigns a public input at the given instance row to the current row
+    pub fn assign_from_instance(
+        &self,
+        ctx: &mut RegionCtx<'_, F>,
+        row: usize,
+    ) -> Result<AssignedValue<F>, Error> {
+        let cell = ctx.assign_advice_from_instance(
+            || "assign from instance",
+            self.config.instance,
+            row,
+            self.config.a,
+        )?;
+        // proceed to the next row
+        self.no_operation(ctx)?;
+        Ok(cell)
+    }
+
     /// Configures polynomial relationships and returns the resuiting config
     pub fn configure(meta: &mut ConstraintSystem<F>) -> MainGateConfig {
         let a = meta.advice_column();